@@ -8,10 +8,46 @@
 //! let now = Timestamp::now();
 //! println!("{}", now);
 //! ```
+//!
+//! # Features
+//!
+//! The crate is `no_std` when the default `std` feature is disabled. The core
+//! `Timestamp` API, arithmetic, calendar helpers and `format_to_slice` only
+//! need `core`; without `alloc`, `Error` is just a code whose message comes
+//! from a static table. `alloc` adds the `String`-based formatting, the `&str`
+//! parsers and owned error messages; `std` (default) adds `SystemTime` interop
+//! and `std::error::Error`.
+//!
+//! On `wasm32` targets the `wasm` feature makes `Timestamp::now` read the
+//! browser clock through `js_sys::Date::now()`, at millisecond resolution.
+//! Formatting, parsing and arithmetic still go through the C library, which
+//! must be built for the same target. `now_monotonic`, `Monotonic`,
+//! `get_clock_precision` and the `SystemTime` conversions keep using the C or
+//! `std` clock and are unavailable in the browser.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use std::ffi::{CStr, CString};
-use std::fmt;
-use std::os::raw::{c_char, c_int};
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::ffi::CString;
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ffi::{c_char, c_int, CStr};
+use core::fmt;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicI64, Ordering};
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 // --- FFI Bindings ---
 
@@ -27,17 +63,35 @@ type ut_error_t = c_int;
 #[allow(non_camel_case_types)]
 type ut_precision_t = c_int;
 
-#[allow(dead_code)]
-const UT_MAX_STRING_LEN: usize = 32;
+/// Buffer size that fits any formatted timestamp plus a NUL terminator.
+pub const UT_MAX_STRING_LEN: usize = 32;
 
 #[allow(dead_code)]
 const UT_OK: ut_error_t = 0;
 
+const UT_ERR_INVALID_FORMAT: ut_error_t = 1;
+const UT_ERR_INVALID_DATE: ut_error_t = 2;
+const UT_ERR_OUT_OF_RANGE: ut_error_t = 3;
+const UT_ERR_UNSUPPORTED_OFFSET: ut_error_t = 4;
+const UT_ERR_FRACTION_TOO_LONG: ut_error_t = 5;
+const UT_ERR_LEAP_SECOND: ut_error_t = 6;
+const UT_ERR_NULL_POINTER: ut_error_t = 7;
+
+// Wrapper-level error codes (negative, outside the C enum).
+const UT_ERR_INVALID_CSTRING: ut_error_t = -1;
+const UT_ERR_OVERFLOW: ut_error_t = -2;
+const UT_ERR_BUFFER_TOO_SMALL: ut_error_t = -3;
+const UT_ERR_UNSUPPORTED_CALENDAR: ut_error_t = -4;
+const UT_ERR_UNKNOWN_TIME_ZONE: ut_error_t = -5;
+const UT_ERR_CLOCK_UNAVAILABLE: ut_error_t = -6;
+
 extern "C" {
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
     fn ut_now() -> ut_timestamp_t;
     fn ut_now_monotonic() -> ut_timestamp_t;
     fn ut_format(ts: ut_timestamp_t, buf: *mut c_char, buf_size: usize, include_nanos: bool) -> c_int;
     fn ut_parse_strict(str: *const c_char, out: *mut ut_timestamp_t) -> ut_error_t;
+    #[cfg(feature = "alloc")]
     fn ut_parse_lenient(str: *const c_char, out: *mut ut_timestamp_t) -> ut_error_t;
     fn ut_from_unix_nanos(nanos: i64) -> ut_timestamp_t;
     fn ut_to_unix_nanos(ts: ut_timestamp_t) -> i64;
@@ -54,56 +108,497 @@ extern "C" {
     
     fn ut_to_japanese_era(ts: ut_timestamp_t, era: *mut c_int, era_year: *mut c_int) -> ut_error_t;
     fn ut_japanese_era_name(era: c_int) -> *const c_char;
-    
-    fn ut_to_iso_week(ts: ut_timestamp_t, year: *mut c_int, week: *mut c_int, day: *mut c_int);
+}
+
+// --- Civil Date Helpers ---
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+const SECONDS_PER_DAY: i64 = 86_400;
+const NANOS_PER_DAY: i64 = NANOS_PER_SECOND * SECONDS_PER_DAY;
+
+/// Earliest instant whose formatted string parses back: the C parser scales
+/// whole seconds to nanoseconds before adding the fraction, which overflows below this.
+const MIN_ROUND_TRIP_NANOS: i64 = i64::MIN / NANOS_PER_SECOND * NANOS_PER_SECOND;
+
+/// Broken-down UTC fields of a timestamp.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Civil {
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanosecond: u32,
+}
+
+/// Convert days since 1970-01-01 to a proleptic Gregorian (year, month, day).
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year as i32, month, day)
+}
+
+/// Convert a proleptic Gregorian (year, month, day) to days since 1970-01-01.
+const fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let y = year as i64 - if month <= 2 { 1 } else { 0 };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Days in `month` of `year`, or 0 if the month is out of range.
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Weekday index of a day count, with Monday = 0. 1970-01-01 was a Thursday.
+fn weekday_index(days: i64) -> u32 {
+    (days + 3).rem_euclid(7) as u32
+}
+
+/// Number of ISO weeks (52 or 53) in the ISO week-numbering year.
+fn iso_weeks_in_year(year: i32) -> u32 {
+    let jan1 = weekday_index(days_from_civil(year, 1, 1));
+    if jan1 == 3 || (jan1 == 2 && is_leap_year(year)) {
+        53
+    } else {
+        52
+    }
+}
+
+const MONTH_ABBR: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+const WEEKDAY_ABBR: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+const WEEKDAY_NAMES: [&str; 7] =
+    ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+
+/// Obsolete RFC 2822 zone names and their offsets in hours.
+const RFC2822_ZONES: [(&str, i32); 11] = [
+    ("UT", 0),
+    ("GMT", 0),
+    ("Z", 0),
+    ("EST", -5),
+    ("EDT", -4),
+    ("CST", -6),
+    ("CDT", -5),
+    ("MST", -7),
+    ("MDT", -6),
+    ("PST", -8),
+    ("PDT", -7),
+];
+
+/// Parse a `±HH:MM` offset to minutes east of UTC.
+fn parse_numeric_offset(zone: &str) -> Result<i32> {
+    let invalid = || Error::new(UT_ERR_INVALID_FORMAT);
+    let b = zone.as_bytes();
+    if !zone.is_ascii() || b.len() != 6 || (b[0] != b'+' && b[0] != b'-') || b[3] != b':' {
+        return Err(invalid());
+    }
+    let hh = parse_digits(&zone[1..3]).ok_or_else(invalid)?;
+    let mm = parse_digits(&zone[4..]).ok_or_else(invalid)?;
+    if hh > 23 || mm > 59 {
+        return Err(Error::new(UT_ERR_OUT_OF_RANGE));
+    }
+    let minutes = (hh * 60 + mm) as i32;
+    Ok(if b[0] == b'-' { -minutes } else { minutes })
+}
+
+/// Parse an RFC 2822 zone (`+hhmm`, `-hhmm` or an obsolete name) to minutes east of UTC.
+fn parse_rfc2822_zone(zone: &str) -> Option<i32> {
+    if !zone.is_ascii() {
+        return None;
+    }
+    let b = zone.as_bytes();
+    if b.len() == 5 && (b[0] == b'+' || b[0] == b'-') {
+        let hh = parse_digits(&zone[1..3])? as i32;
+        let mm = parse_digits(&zone[3..])? as i32;
+        if hh > 23 || mm > 59 {
+            return None;
+        }
+        let minutes = hh * 60 + mm;
+        return Some(if b[0] == b'-' { -minutes } else { minutes });
+    }
+    if let Some(&(_, hours)) = RFC2822_ZONES.iter().find(|(name, _)| name.eq_ignore_ascii_case(zone)) {
+        return Some(hours * 60);
+    }
+    // Military zones are unreliable and treated as UTC (RFC 5322 section 4.3).
+    if b.len() == 1 && b[0].is_ascii_alphabetic() && !b[0].eq_ignore_ascii_case(&b'J') {
+        return Some(0);
+    }
+    None
+}
+
+/// Parse an optionally negative run of ASCII digits as `i64`.
+fn parse_signed(s: &str) -> Result<i64> {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::new(UT_ERR_INVALID_FORMAT));
+    }
+    s.parse().map_err(|_| Error::new(UT_ERR_OUT_OF_RANGE))
+}
+
+/// Byte offset at which `s` first departs from the strict
+/// `YYYY-MM-DDTHH:MM:SS[.fffffffff]Z` grammar, or `None` if it conforms.
+///
+/// An out-of-range field points at the start of that field, and an
+/// impossible date such as February 30 points at the day.
+fn strict_error_position(s: &[u8]) -> Option<usize> {
+    const LAYOUT: &[u8; 19] = b"dddd-dd-ddTdd:dd:dd";
+    for (i, &expected) in LAYOUT.iter().enumerate() {
+        let ok = match s.get(i) {
+            Some(&c) if expected == b'd' => c.is_ascii_digit(),
+            Some(&c) => c == expected,
+            None => false,
+        };
+        if !ok {
+            return Some(i);
+        }
+    }
+    let field = |start: usize, len: usize| {
+        s[start..start + len].iter().fold(0u32, |acc, &d| acc * 10 + (d - b'0') as u32)
+    };
+    let (year, month, day) = (field(0, 4) as i32, field(5, 2), field(8, 2));
+    if !(1..=12).contains(&month) {
+        return Some(5);
+    }
+    if day == 0 || day > days_in_month(year, month) {
+        return Some(8);
+    }
+    for start in [11, 14, 17] {
+        let max = if start == 11 { 23 } else { 59 };
+        if field(start, 2) > max {
+            return Some(start);
+        }
+    }
+    let mut pos = 19;
+    if s.get(pos) == Some(&b'.') {
+        pos += 1;
+        let digits = s[pos..].iter().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 || digits > 9 {
+            return Some(pos + digits.min(9));
+        }
+        pos += digits;
+    }
+    if s.get(pos) != Some(&b'Z') {
+        return Some(pos);
+    }
+    if pos + 1 != s.len() {
+        return Some(pos + 1);
+    }
+    None
+}
+
+/// Parse a run of ASCII digits. Signs and whitespace are rejected.
+fn parse_digits(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
 }
 
 // --- Wrapper Implementation ---
 
+/// Error returned by fallible operations: a numeric code plus a description.
+///
+/// With `alloc` the description is an owned `String`. Without it only the
+/// code and parse position are stored and `message` returns a `&'static str`
+/// from a static table, so errors never touch the heap.
 #[derive(Debug, Clone)]
 pub struct Error {
     code: ut_error_t,
+    position: Option<usize>,
+    #[cfg(feature = "alloc")]
     message: String,
 }
 
+/// Category of an `Error`, for matching without inspecting the message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Input was rejected by the strict parser for its content: a date that
+    /// does not exist (e.g. February 30), a non-UTC offset, more than nine
+    /// fractional digits, or a leap second.
+    ParseFailure,
+    /// Input does not match the expected pattern, contains a NUL, or was a
+    /// null pointer on the C side.
+    InvalidFormat,
+    /// A component or result lies outside its valid range.
+    OutOfRange,
+    /// Arithmetic would overflow the `i64` nanosecond range.
+    Overflow,
+    /// A caller-provided buffer cannot hold the output.
+    BufferTooSmall,
+    /// The requested calendar conversion is not supported.
+    UnsupportedCalendar,
+    /// The time zone name is not recognized.
+    UnknownTimeZone,
+    /// The system clock returned an unusable reading.
+    ClockUnavailable,
+    /// A code not known to this wrapper.
+    Unknown(i32),
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.message)
+        f.write_str(self.message())
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
+/// Errors compare by code; the message is derived from it.
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+    }
+}
+
+impl Eq for Error {}
+
 impl Error {
+    #[cfg(not(feature = "alloc"))]
+    fn new(code: ut_error_t) -> Self {
+        Error { code, position: None }
+    }
+
+    #[cfg(feature = "alloc")]
     fn new(code: ut_error_t) -> Self {
-        let msg_ptr = unsafe { ut_error_string(code) };
-        let message = unsafe { CStr::from_ptr(msg_ptr) }.to_string_lossy().into_owned();
-        Error { code, message }
+        Error { code, position: None, message: Error::static_message(code).to_string() }
+    }
+
+    /// Description of `code`: wrapper codes from this table, C codes from `ut_error_string`.
+    fn static_message(code: ut_error_t) -> &'static str {
+        match code {
+            UT_ERR_INVALID_CSTRING => "Invalid C string",
+            UT_ERR_OVERFLOW => "Arithmetic overflow",
+            UT_ERR_BUFFER_TOO_SMALL => "Buffer too small",
+            UT_ERR_UNSUPPORTED_CALENDAR => "Unsupported calendar",
+            UT_ERR_UNKNOWN_TIME_ZONE => "Unknown time zone",
+            UT_ERR_CLOCK_UNAVAILABLE => "Clock unavailable",
+            // ut_error_string returns pointers to string literals.
+            _ => unsafe { CStr::from_ptr(ut_error_string(code)) }
+                .to_str()
+                .unwrap_or("Unknown error"),
+        }
+    }
+
+    fn at(mut self, position: Option<usize>) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Raw error code: the C `ut_error_t` value, or a negative wrapper-level code.
+    pub fn code(&self) -> i32 {
+        self.code
+    }
+
+    /// Byte offset in the input where strict parsing failed, if known.
+    ///
+    /// Set by `parse`, `parse_cstr` and `parse_many`. The C parser does not
+    /// report positions, so the offset comes from a separate scan of the
+    /// input against the strict grammar.
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Human-readable description of the error.
+    #[cfg(feature = "alloc")]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Human-readable description of the error, from a static table.
+    #[cfg(not(feature = "alloc"))]
+    pub fn message(&self) -> &str {
+        Error::static_message(self.code)
+    }
+
+    /// Classify the error code.
+    pub fn kind(&self) -> ErrorKind {
+        match self.code {
+            UT_ERR_INVALID_FORMAT | UT_ERR_INVALID_CSTRING | UT_ERR_NULL_POINTER => {
+                ErrorKind::InvalidFormat
+            }
+            UT_ERR_INVALID_DATE
+            | UT_ERR_UNSUPPORTED_OFFSET
+            | UT_ERR_FRACTION_TOO_LONG
+            | UT_ERR_LEAP_SECOND => ErrorKind::ParseFailure,
+            UT_ERR_OUT_OF_RANGE => ErrorKind::OutOfRange,
+            UT_ERR_OVERFLOW => ErrorKind::Overflow,
+            UT_ERR_BUFFER_TOO_SMALL => ErrorKind::BufferTooSmall,
+            UT_ERR_UNSUPPORTED_CALENDAR => ErrorKind::UnsupportedCalendar,
+            UT_ERR_UNKNOWN_TIME_ZONE => ErrorKind::UnknownTimeZone,
+            UT_ERR_CLOCK_UNAVAILABLE => ErrorKind::ClockUnavailable,
+            other => ErrorKind::Unknown(other),
+        }
     }
 }
 
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A UTC instant as signed nanoseconds since the Unix epoch.
+///
+/// The type is `#[repr(transparent)]` over the C `ut_timestamp_t`, so it has
+/// the size and alignment of an `i64` and can be passed by value to and from
+/// C code taking `ut_timestamp_t`. This layout is part of the stable API.
+///
+/// Every `Timestamp` is a wall-clock (UTC) reading, including those from
+/// `now_monotonic`, so comparing values from `now`, `now_monotonic` and
+/// `parse` is meaningful. Interval readings belong to the separate
+/// `Monotonic` type, which cannot be mixed with `Timestamp` at compile time.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
 pub struct Timestamp {
     inner: ut_timestamp_t,
 }
 
 impl Timestamp {
+    /// The Unix epoch, 1970-01-01T00:00:00Z.
+    pub const EPOCH: Timestamp = Timestamp { inner: ut_timestamp_t { nanos: 0 } };
+
+    /// The earliest representable instant (`i64::MIN` nanoseconds).
+    pub const MIN: Timestamp = Timestamp { inner: ut_timestamp_t { nanos: i64::MIN } };
+
+    /// The latest representable instant (`i64::MAX` nanoseconds).
+    pub const MAX: Timestamp = Timestamp { inner: ut_timestamp_t { nanos: i64::MAX } };
+
+    /// Start of the `is_plausible` window, 1971-01-01T00:00:00Z.
+    pub const PLAUSIBLE_MIN: Timestamp = Timestamp::from_nanos_const(31_536_000 * NANOS_PER_SECOND);
+
+    /// End (exclusive) of the `is_plausible` window, 2200-01-01T00:00:00Z.
+    pub const PLAUSIBLE_MAX: Timestamp = Timestamp::from_nanos_const(7_258_118_400 * NANOS_PER_SECOND);
+
     /// Get the current UTC time.
+    ///
+    /// Never fails; see `try_now` to reject implausible clock readings.
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
     pub fn now() -> Self {
         unsafe {
              Timestamp { inner: ut_now() }
         }
     }
 
+    /// Get the current UTC time from the JavaScript `Date.now()` clock.
+    ///
+    /// Never fails; see `try_now` to reject implausible clock readings.
+    /// The result has millisecond resolution.
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    pub fn now() -> Self {
+        Timestamp::from_nanos(js_sys::Date::now() as i64 * 1_000_000)
+    }
+
+    /// Get the current UTC time, failing if the reading is implausible.
+    ///
+    /// The C clock call reports no errors, so this rejects readings at or
+    /// before the Unix epoch, which is what a failed or unset clock yields.
+    pub fn try_now() -> Result<Self> {
+        let ts = Timestamp::now();
+        if ts.as_nanos() <= 0 {
+            return Err(Error::new(UT_ERR_CLOCK_UNAVAILABLE));
+        }
+        Ok(ts)
+    }
+
+    /// Get the current UTC time, truncated to the detected `get_clock_precision()`.
+    ///
+    /// Digits the clock cannot resolve are zero rather than noise. Detection
+    /// samples the clock on every call, so prefer `now` in hot paths.
+    pub fn now_truncated() -> Self {
+        let precision = get_clock_precision();
+        Timestamp::now().truncate_to(precision)
+    }
+
     /// Get the current UTC time with monotonic guarantee.
+    ///
+    /// This is the wall clock, bumped by a nanosecond whenever it would not
+    /// advance, so the result is directly comparable with `now()`. It still
+    /// follows steps in the system clock; use `Monotonic` to measure elapsed
+    /// time.
     pub fn now_monotonic() -> Self {
         unsafe {
             Timestamp { inner: ut_now_monotonic() }
         }
     }
 
+    /// Create from UTC calendar components.
+    ///
+    /// Returns an error for impossible dates (e.g. February 30), out-of-range
+    /// time fields, or instants outside the `i64` nanosecond range.
+    pub fn from_ymd_hms(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nanos: u32,
+    ) -> Result<Self> {
+        if day == 0 || day > days_in_month(year, month) {
+            return Err(Error::new(UT_ERR_INVALID_DATE));
+        }
+        if hour > 23 || minute > 59 || second > 59 || nanos as i64 >= NANOS_PER_SECOND {
+            return Err(Error::new(UT_ERR_OUT_OF_RANGE));
+        }
+        let secs = days_from_civil(year, month, day) as i128 * SECONDS_PER_DAY as i128
+            + (hour * 3600 + minute * 60 + second) as i128;
+        let total = secs * NANOS_PER_SECOND as i128 + nanos as i128;
+        let total = i64::try_from(total).map_err(|_| Error::new(UT_ERR_OUT_OF_RANGE))?;
+        Ok(Timestamp::from_nanos(total))
+    }
+
+    /// Create from local calendar components at `offset_minutes` east of UTC.
+    ///
+    /// `from_ymd_hms_offset(2024, 12, 14, 14, 0, 0, 0, 120)` is
+    /// 2024-12-14T12:00:00Z. The offset is signed, matching
+    /// `parse_with_offset`, and must be less than 24 hours in magnitude.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_ymd_hms_offset(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nanos: u32,
+        offset_minutes: i32,
+    ) -> Result<Self> {
+        if offset_minutes.unsigned_abs() >= 24 * 60 {
+            return Err(Error::new(UT_ERR_OUT_OF_RANGE));
+        }
+        let local = Timestamp::from_ymd_hms(year, month, day, hour, minute, second, nanos)?;
+        local.checked_sub_nanos(offset_minutes as i64 * 60 * NANOS_PER_SECOND)
+    }
+
     /// Create from Unix nanoseconds.
     pub fn from_nanos(nanos: i64) -> Self {
         unsafe {
@@ -111,134 +606,2384 @@ impl Timestamp {
         }
     }
 
+    /// Create from Unix nanoseconds in a `const` context.
+    ///
+    /// Equivalent to `from_nanos`: `ut_from_unix_nanos` stores the value
+    /// unchanged, so this builds the struct directly instead of calling C.
+    pub const fn from_nanos_const(nanos: i64) -> Self {
+        Timestamp { inner: ut_timestamp_t { nanos } }
+    }
+
+    /// Compare two timestamps in a `const` context, e.g. to check at compile
+    /// time that a static schedule is sorted. Agrees with `Ord`.
+    pub const fn const_cmp(a: Timestamp, b: Timestamp) -> core::cmp::Ordering {
+        if a.inner.nanos < b.inner.nanos {
+            core::cmp::Ordering::Less
+        } else if a.inner.nanos > b.inner.nanos {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }
+
+    /// Create from Unix nanoseconds, or `None` if the result is not `is_plausible`.
+    pub fn from_nanos_checked(nanos: i64) -> Option<Self> {
+        Some(Timestamp::from_nanos(nanos)).filter(Timestamp::is_plausible)
+    }
+
+    /// Whether the instant lies in `[PLAUSIBLE_MIN, PLAUSIBLE_MAX)`, 1971 to 2199.
+    ///
+    /// Seconds, milliseconds or microseconds misread as nanoseconds all land
+    /// in January 1970, so this catches unit mix-ups. For a different window,
+    /// use `(min..max).contains(&ts)`.
+    pub fn is_plausible(&self) -> bool {
+        (Timestamp::PLAUSIBLE_MIN..Timestamp::PLAUSIBLE_MAX).contains(self)
+    }
+
     /// Get nanoseconds since Unix epoch.
     pub fn as_nanos(&self) -> i64 {
         unsafe { ut_to_unix_nanos(self.inner) }
     }
 
-    /// Parse ISO-8601 string (strict).
-    pub fn parse(s: &str) -> Result<Self> {
-        let c_str = CString::new(s).map_err(|_| Error { code: -1, message: "Invalid C string".to_string() })?;
-        let mut ts = ut_timestamp_t { nanos: 0 };
-        let err = unsafe { ut_parse_strict(c_str.as_ptr(), &mut ts) };
-        if err != UT_OK {
-            return Err(Error::new(err));
+    /// Low-level accessor for handing the value across another FFI boundary;
+    /// same as `as_nanos`.
+    pub fn into_raw_nanos(self) -> i64 {
+        self.inner.nanos
+    }
+
+    /// Low-level constructor from a raw `ut_timestamp_t` nanosecond value;
+    /// same as `from_nanos`.
+    pub const fn from_raw_nanos(nanos: i64) -> Timestamp {
+        Timestamp::from_nanos_const(nanos)
+    }
+
+    /// Reinterpret a slice of Unix nanoseconds as timestamps, without copying.
+    pub fn from_nanos_slice(nanos: &[i64]) -> &[Timestamp] {
+        // SAFETY: `Timestamp` is `repr(transparent)` over the `repr(C)`
+        // single-`i64` `ut_timestamp_t`, so it has the size and alignment of
+        // `i64` (checked below), and every `i64` is a valid timestamp. The
+        // length and lifetime are carried over from the input slice.
+        unsafe { core::slice::from_raw_parts(nanos.as_ptr() as *const Timestamp, nanos.len()) }
+    }
+
+    /// Reinterpret a slice of timestamps as Unix nanoseconds, without copying.
+    pub fn as_nanos_slice(timestamps: &[Timestamp]) -> &[i64] {
+        // SAFETY: the same layout argument as `from_nanos_slice`, in reverse.
+        unsafe { core::slice::from_raw_parts(timestamps.as_ptr() as *const i64, timestamps.len()) }
+    }
+
+    /// Iterate from `start` (inclusive) to `end` (exclusive) in increments of `step`.
+    ///
+    /// A zero `step` or `start >= end` yields an empty iterator.
+    pub fn range(start: Timestamp, end: Timestamp, step: Duration) -> TimestampRange {
+        TimestampRange {
+            next: if step.is_zero() { None } else { Some(start) },
+            end,
+            step: step.as_nanos() as i128,
         }
-        Ok(Timestamp { inner: ts })
     }
 
-    /// Parse ISO-8601 string (lenient).
-    pub fn parse_lenient(s: &str) -> Result<Self> {
-        let c_str = CString::new(s).map_err(|_| Error { code: -1, message: "Invalid C string".to_string() })?;
-        let mut ts = ut_timestamp_t { nanos: 0 };
-        let err = unsafe { ut_parse_lenient(c_str.as_ptr(), &mut ts) };
-        if err != UT_OK {
-            return Err(Error::new(err));
+    /// Yield the items of `range(start, end, step)` in descending order.
+    ///
+    /// Iteration begins at the last `start + k * step` before `end` and ends
+    /// at `start`. A zero `step` or `start >= end` yields an empty iterator.
+    pub fn range_rev(start: Timestamp, end: Timestamp, step: Duration) -> TimestampRangeRev {
+        let step = step.as_nanos() as i128;
+        let span = end.as_nanos() as i128 - start.as_nanos() as i128;
+        let next = if step == 0 || span <= 0 {
+            None
+        } else {
+            // Below `end`, so always within the i64 range.
+            let last = start.as_nanos() as i128 + (span - 1) / step * step;
+            Some(Timestamp::from_nanos(last as i64))
+        };
+        TimestampRangeRev { next, start, step }
+    }
+
+    /// Start of each UTC month overlapping `[start, end)`, in order.
+    ///
+    /// The first item is the start of `start`'s month, which may precede
+    /// `start`. Yields nothing when `start >= end`.
+    pub fn months_between(start: Timestamp, end: Timestamp) -> impl Iterator<Item = Timestamp> {
+        let first = if start < end { Some(start.start_of_month()) } else { None };
+        core::iter::successors(first, move |month| {
+            // Re-snap to the 1st in case the first month saturated at `MIN`.
+            let next = month.add_months(1).ok()?.start_of_month();
+            if next < end {
+                Some(next)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Add nanoseconds, failing instead of wrapping on `i64` overflow.
+    pub fn checked_add_nanos(&self, nanos: i64) -> Result<Timestamp> {
+        self.as_nanos()
+            .checked_add(nanos)
+            .map(Timestamp::from_nanos)
+            .ok_or_else(|| Error::new(UT_ERR_OVERFLOW))
+    }
+
+    /// Subtract nanoseconds, failing instead of wrapping on `i64` overflow.
+    pub fn checked_sub_nanos(&self, nanos: i64) -> Result<Timestamp> {
+        self.as_nanos()
+            .checked_sub(nanos)
+            .map(Timestamp::from_nanos)
+            .ok_or_else(|| Error::new(UT_ERR_OVERFLOW))
+    }
+
+    /// Add nanoseconds, clamping at `i64::MIN`/`i64::MAX`.
+    pub fn saturating_add_nanos(&self, nanos: i64) -> Timestamp {
+        Timestamp::from_nanos(self.as_nanos().saturating_add(nanos))
+    }
+
+    /// Signed nanoseconds from `other` to `self` (`self - other`).
+    ///
+    /// Saturates at `i64::MIN`/`i64::MAX` when the true gap does not fit,
+    /// e.g. between `Timestamp::MIN` and `Timestamp::MAX`.
+    pub fn signed_duration_nanos_since(&self, other: Timestamp) -> i64 {
+        self.as_nanos().saturating_sub(other.as_nanos())
+    }
+
+    /// Absolute gap between `self` and `other`. Never overflows.
+    pub fn diff(&self, other: Timestamp) -> Duration {
+        *self - other
+    }
+
+    /// Time from `earlier` to `self`, or `None` if `earlier` is later.
+    ///
+    /// Mirrors `std::time::Instant::checked_duration_since`.
+    pub fn checked_duration_since(&self, earlier: Timestamp) -> Option<Duration> {
+        if earlier > *self {
+            return None;
         }
-        Ok(Timestamp { inner: ts })
+        Some(*self - earlier)
     }
 
-    /// Format to ISO-8601 string.
-    pub fn format(&self, include_nanos: bool) -> String {
-        let mut buf = vec![0u8; UT_MAX_STRING_LEN];
-        unsafe {
-             ut_format(self.inner, buf.as_mut_ptr() as *mut c_char, buf.len(), include_nanos);
+    /// Wall-clock time since this instant, or zero if it is in the future.
+    ///
+    /// Reads `Timestamp::now()` on every call.
+    pub fn elapsed(&self) -> Duration {
+        let now = Timestamp::now();
+        if now > *self {
+            now - *self
+        } else {
+            Duration::ZERO
         }
-        let end = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
-        String::from_utf8_lossy(&buf[..end]).into_owned()
     }
 
-    pub fn to_iso_week(&self) -> (i32, i32, i32) {
-        let mut year = 0;
-        let mut week = 0;
-        let mut day = 0;
-        unsafe {
-            ut_to_iso_week(self.inner, &mut year, &mut week, &mut day);
+    /// Wall-clock time remaining until this instant, or `None` if it has passed.
+    ///
+    /// Reads `Timestamp::now()` on every call.
+    pub fn until(&self) -> Option<Duration> {
+        let now = Timestamp::now();
+        if *self > now {
+            Some(*self - now)
+        } else {
+            None
         }
-        (year, week, day)
     }
-    
-    pub fn to_japanese_era(&self) -> Result<(i32, i32, String)> {
-        let mut era = 0;
-        let mut year = 0;
-        let err = unsafe { ut_to_japanese_era(self.inner, &mut era, &mut year) };
-        if err != UT_OK {
-            return Err(Error::new(err));
+
+    /// UTC midnights crossed going from this instant to `other`: positive if
+    /// `other` is later, negative if earlier.
+    ///
+    /// Counts calendar dates rather than 24-hour spans, so 23:00 to 01:00 the
+    /// next day is one day and 00:00 to 23:59 the same day is zero.
+    pub fn calendar_days_between(&self, other: Timestamp) -> i64 {
+        other.unix_days() - self.unix_days()
+    }
+
+    /// Whole years, months and days from `self` to `other`, counted the way
+    /// ages are.
+    ///
+    /// Months are added with `add_months` clamping, so January 31 to February
+    /// 28 is one month and February 29, 2024 to February 28, 2025 is one year.
+    /// A day counts only once complete, so the time of day matters. If
+    /// `other` is earlier, every field is negated.
+    pub fn calendar_diff(&self, other: Timestamp) -> CalendarDelta {
+        if other < *self {
+            let d = other.calendar_diff(*self);
+            return CalendarDelta { years: -d.years, months: -d.months, days: -d.days };
+        }
+        let (a, b) = (self.civil(), other.civil());
+        let guess = (b.year as i64 - a.year as i64) * 12 + (b.month as i64 - a.month as i64);
+        let months = match self.shift_months(guess) {
+            Ok(ts) if ts <= other => guess,
+            _ => guess - 1,
+        };
+        // Always in range: it lies between `self` and `other`.
+        let anchor = self.shift_months(months).unwrap_or(*self);
+        let days = (other.as_nanos() as i128 - anchor.as_nanos() as i128) / NANOS_PER_DAY as i128;
+        CalendarDelta {
+            years: (months / 12) as i32,
+            months: (months % 12) as i32,
+            days: days as i32,
         }
-        let name_ptr = unsafe { ut_japanese_era_name(era) };
-        let name = unsafe { CStr::from_ptr(name_ptr) }.to_string_lossy().into_owned();
-        Ok((era, year, name))
     }
-}
 
-impl fmt::Display for Timestamp {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.format(true))
+    /// Whether both instants fall on the same UTC calendar day.
+    pub fn same_day(&self, other: Timestamp) -> bool {
+        self.unix_days() == other.unix_days()
     }
-}
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum Precision {
-    Nanosecond = 0,
-    Microsecond = 1,
-    Millisecond = 2,
-    Second = 3,
-    Error = -1,
-}
+    /// Whether both instants fall in the same UTC calendar month of the same year.
+    pub fn same_month(&self, other: Timestamp) -> bool {
+        let (a, b) = (self.civil(), other.civil());
+        (a.year, a.month) == (b.year, b.month)
+    }
 
-pub fn get_clock_precision() -> Precision {
-    let p = unsafe { ut_get_clock_precision() };
-    match p {
-        0 => Precision::Nanosecond,
-        1 => Precision::Microsecond,
-        2 => Precision::Millisecond,
-        3 => Precision::Second,
-        _ => Precision::Error,
+    /// Whether both instants fall in the same UTC calendar year.
+    pub fn same_year(&self, other: Timestamp) -> bool {
+        self.year() == other.year()
     }
-}
 
-pub mod calendar {
-    use super::*;
+    /// Whether the two instants are at most `tolerance` apart, in either order.
+    pub fn approx_eq(&self, other: Timestamp, tolerance: Duration) -> bool {
+        self.as_nanos().abs_diff(other.as_nanos()) as u128 <= tolerance.as_nanos()
+    }
 
-    pub fn gregorian_to_thai(year: i32) -> i32 {
-        unsafe { ut_gregorian_to_thai(year) }
+    /// Restrict to `[min, max]`, as `Ord::clamp`. Panics if `min > max`.
+    pub fn clamp(self, min: Timestamp, max: Timestamp) -> Timestamp {
+        Ord::clamp(self, min, max)
     }
-    pub fn thai_to_gregorian(year: i32) -> i32 {
-        unsafe { ut_thai_to_gregorian(year) }
+
+    /// The earlier of two timestamps.
+    pub fn min(self, other: Timestamp) -> Timestamp {
+        Ord::min(self, other)
     }
-    pub fn gregorian_to_dangi(year: i32) -> i32 {
-        unsafe { ut_gregorian_to_dangi(year) }
+
+    /// The later of two timestamps.
+    pub fn max(self, other: Timestamp) -> Timestamp {
+        Ord::max(self, other)
     }
-    pub fn dangi_to_gregorian(year: i32) -> i32 {
-        unsafe { ut_dangi_to_gregorian(year) }
+
+    /// Zero out the digits below `precision`, rounding toward negative infinity.
+    ///
+    /// Pre-epoch values therefore move earlier. `Precision::Error` returns `self`
+    /// unchanged, and results below `i64::MIN` saturate.
+    pub fn truncate_to(&self, precision: Precision) -> Timestamp {
+        let unit = match precision.unit_nanos() {
+            Some(unit) => unit,
+            None => return *self,
+        };
+        let q = self.as_nanos().div_euclid(unit) as i128;
+        Timestamp::from_nanos(saturate_nanos(q * unit as i128))
     }
-    pub fn gregorian_to_minguo(year: i32) -> i32 {
-        unsafe { ut_gregorian_to_minguo(year) }
+
+    /// Round to the nearest multiple of `precision`, with ties to even.
+    ///
+    /// `Precision::Error` returns `self` unchanged, and results beyond the
+    /// `i64` range saturate.
+    pub fn round_to(&self, precision: Precision) -> Timestamp {
+        let unit = match precision.unit_nanos() {
+            Some(unit) => unit,
+            None => return *self,
+        };
+        let nanos = self.as_nanos();
+        let mut q = nanos.div_euclid(unit) as i128;
+        let r = nanos.rem_euclid(unit) as i128;
+        let unit = unit as i128;
+        if 2 * r > unit || (2 * r == unit && q % 2 != 0) {
+            q += 1;
+        }
+        Timestamp::from_nanos(saturate_nanos(q * unit))
     }
-    pub fn minguo_to_gregorian(year: i32) -> i32 {
-        unsafe { ut_minguo_to_gregorian(year) }
+
+    /// Snap to a multiple of `n` UTC wall-clock minutes, zeroing seconds and below.
+    ///
+    /// `n` must divide 60 evenly (1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30 or 60),
+    /// otherwise this fails with an out-of-range error. `SnapMode::Nearest`
+    /// rounds ties up. Fails with an overflow error past the `i64` range.
+    pub fn snap_to_minutes(&self, n: u32, mode: SnapMode) -> Result<Timestamp> {
+        if n == 0 || 60 % n != 0 {
+            return Err(Error::new(UT_ERR_OUT_OF_RANGE));
+        }
+        // Days start on a whole hour, so epoch-aligned buckets are wall-clock aligned.
+        let unit = n as i128 * 60 * NANOS_PER_SECOND as i128;
+        let nanos = self.as_nanos() as i128;
+        let floor = nanos - nanos.rem_euclid(unit);
+        let snapped = match mode {
+            SnapMode::Floor => floor,
+            SnapMode::Ceil if floor == nanos => floor,
+            SnapMode::Ceil => floor + unit,
+            SnapMode::Nearest if 2 * (nanos - floor) >= unit => floor + unit,
+            SnapMode::Nearest => floor,
+        };
+        i64::try_from(snapped)
+            .map(Timestamp::from_nanos)
+            .map_err(|_| Error::new(UT_ERR_OVERFLOW))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Scale `value` by `factor` nanoseconds, failing if it overflows `i64`.
+    fn from_scaled(value: i64, factor: i64) -> Result<Self> {
+        value
+            .checked_mul(factor)
+            .map(Timestamp::from_nanos)
+            .ok_or_else(|| Error::new(UT_ERR_OUT_OF_RANGE))
+    }
 
-    #[test]
-    fn test_now() {
-        let _ = Timestamp::now();
+    /// Create from Unix seconds. Fails if the value overflows `i64` nanoseconds.
+    pub fn from_unix_seconds(secs: i64) -> Result<Self> {
+        Timestamp::from_scaled(secs, NANOS_PER_SECOND)
     }
 
-    #[test]
-    fn test_format_parse() {
-        let ts = Timestamp::now_monotonic();
-        let s = ts.format(true);
-        let parsed = Timestamp::parse(&s).unwrap();
-        assert_eq!(ts, parsed);
+    /// Create from Unix milliseconds. Fails if the value overflows `i64` nanoseconds.
+    pub fn from_unix_millis(millis: i64) -> Result<Self> {
+        Timestamp::from_scaled(millis, 1_000_000)
     }
-    
-    #[test]
-    fn test_calendar() {
-        assert_eq!(calendar::gregorian_to_thai(2024), 2567);
+
+    /// Create from Unix microseconds. Fails if the value overflows `i64` nanoseconds.
+    pub fn from_unix_micros(micros: i64) -> Result<Self> {
+        Timestamp::from_scaled(micros, 1_000)
+    }
+
+    /// Parse a bare epoch number such as `1734177600` in the given `unit`.
+    ///
+    /// Accepts an optional leading `-` followed by ASCII digits. Other input
+    /// fails with an invalid-format error, and values that overflow `i64`
+    /// nanoseconds with an out-of-range error.
+    pub fn parse_unix(s: &str, unit: Unit) -> Result<Self> {
+        Timestamp::from_unix_unit(parse_signed(s)?, unit)
+    }
+
+    /// Guess the unit of an epoch count from its magnitude.
+    ///
+    /// A heuristic for instants between 1973 and 2262: values below 1e11 are
+    /// seconds, below 1e14 milliseconds, below 1e17 microseconds, and larger
+    /// ones nanoseconds. A present-day count (about 1.7e9 seconds) is
+    /// classified correctly in every unit; counts near 1970 are not.
+    pub fn guess_unix_unit(value: i64) -> Unit {
+        match value.unsigned_abs() {
+            0..=99_999_999_999 => Unit::Seconds,
+            100_000_000_000..=99_999_999_999_999 => Unit::Millis,
+            100_000_000_000_000..=99_999_999_999_999_999 => Unit::Micros,
+            _ => Unit::Nanos,
+        }
+    }
+
+    /// Create from an epoch count in the unit chosen by `guess_unix_unit`.
+    ///
+    /// Fails with `ErrorKind::OutOfRange` whenever the count, read in the
+    /// guessed unit, overflows `i64` nanoseconds (past 2262), which can happen
+    /// in the seconds, millis and micros bands alike.
+    pub fn from_unix_auto(value: i64) -> Result<Self> {
+        Timestamp::from_unix_unit(value, Timestamp::guess_unix_unit(value))
+    }
+
+    fn from_unix_unit(value: i64, unit: Unit) -> Result<Self> {
+        match unit {
+            Unit::Seconds => Timestamp::from_unix_seconds(value),
+            Unit::Millis => Timestamp::from_unix_millis(value),
+            Unit::Micros => Timestamp::from_unix_micros(value),
+            Unit::Nanos => Ok(Timestamp::from_nanos(value)),
+        }
+    }
+
+    /// Get whole seconds since Unix epoch, rounded toward negative infinity.
+    pub fn as_unix_seconds(&self) -> i64 {
+        self.as_nanos().div_euclid(NANOS_PER_SECOND)
+    }
+
+    /// Get whole milliseconds since Unix epoch, rounded toward negative infinity.
+    pub fn as_millis(&self) -> i64 {
+        self.as_nanos().div_euclid(1_000_000)
+    }
+
+    /// Get whole microseconds since Unix epoch, rounded toward negative infinity.
+    pub fn as_micros(&self) -> i64 {
+        self.as_nanos().div_euclid(1_000)
+    }
+
+    /// Time since the Unix epoch, or `None` before it since `Duration` is unsigned.
+    pub fn duration_since_epoch(&self) -> Option<Duration> {
+        u64::try_from(self.as_nanos()).ok().map(Duration::from_nanos)
+    }
+
+    /// Split into `google.protobuf.Timestamp` fields `(seconds, nanos)`.
+    ///
+    /// `nanos` is always in `[0, 999_999_999]`; instants before the epoch
+    /// borrow a second, so -0.5s is `(-1, 500_000_000)`.
+    pub fn as_protobuf(&self) -> (i64, i32) {
+        let nanos = self.as_nanos();
+        (nanos.div_euclid(NANOS_PER_SECOND), nanos.rem_euclid(NANOS_PER_SECOND) as i32)
+    }
+
+    /// Build from `google.protobuf.Timestamp` fields.
+    ///
+    /// Fails if `nanos` is outside `[0, 999_999_999]` or the instant
+    /// overflows `i64` nanoseconds.
+    pub fn from_protobuf(seconds: i64, nanos: i32) -> Result<Self> {
+        if !(0..NANOS_PER_SECOND as i32).contains(&nanos) {
+            return Err(Error::new(UT_ERR_OUT_OF_RANGE));
+        }
+        let total = seconds as i128 * NANOS_PER_SECOND as i128 + nanos as i128;
+        i64::try_from(total)
+            .map(Timestamp::from_nanos)
+            .map_err(|_| Error::new(UT_ERR_OUT_OF_RANGE))
+    }
+
+    /// Seconds since Unix epoch as a float.
+    ///
+    /// An `f64` holds about 16 significant digits, so present-day values keep
+    /// roughly microsecond precision and the nanosecond digits are lost.
+    pub fn as_unix_seconds_f64(&self) -> f64 {
+        let nanos = self.as_nanos();
+        nanos.div_euclid(NANOS_PER_SECOND) as f64 + nanos.rem_euclid(NANOS_PER_SECOND) as f64 / 1e9
+    }
+
+    /// Create from float seconds since Unix epoch, rounded to the nearest nanosecond.
+    ///
+    /// Fails with an out-of-range error for NaN, infinities and values outside
+    /// the `i64` nanosecond range. See `as_unix_seconds_f64` for precision.
+    pub fn from_unix_seconds_f64(secs: f64) -> Result<Self> {
+        let nanos = secs * 1e9;
+        // Round half away from zero; `f64::round` needs std.
+        let nanos = if nanos >= 0.0 { nanos + 0.5 } else { nanos - 0.5 };
+        // i64::MAX is not representable as f64; 2^63 is the first value past it.
+        if !(nanos >= i64::MIN as f64 && nanos < 9_223_372_036_854_775_808.0) {
+            return Err(Error::new(UT_ERR_OUT_OF_RANGE));
+        }
+        Ok(Timestamp::from_nanos(nanos as i64))
+    }
+
+    /// Parse ISO-8601 string (strict).
+    #[cfg(feature = "alloc")]
+    pub fn parse(s: &str) -> Result<Self> {
+        let c_str = CString::new(s)
+            .map_err(|e| Error::new(UT_ERR_INVALID_CSTRING).at(Some(e.nul_position())))?;
+        Timestamp::parse_cstr(&c_str)
+    }
+
+    /// Strictly parse an ISO 8601 timestamp at the start of `s`, returning it
+    /// with the unconsumed rest of the input.
+    ///
+    /// The token ends at its `Z`, so `"2024-12-14T12:00:00Z rest"` leaves
+    /// `" rest"`. If `s` does not start with a complete strict timestamp the
+    /// error is the one `parse` reports for the whole input.
+    #[cfg(feature = "alloc")]
+    pub fn parse_prefix(s: &str) -> Result<(Self, &str)> {
+        let b = s.as_bytes();
+        match strict_error_position(b) {
+            Some(end) if end > 19 && b[end - 1] == b'Z' => {
+                Timestamp::parse(&s[..end]).map(|ts| (ts, &s[end..]))
+            }
+            _ => Timestamp::parse(s).map(|ts| (ts, "")),
+        }
+    }
+
+    /// Strictly parse each input, reporting every result independently.
+    ///
+    /// One scratch buffer is reused for the NUL-terminated copies instead of
+    /// allocating a `CString` per element.
+    #[cfg(feature = "alloc")]
+    pub fn parse_many(inputs: &[&str]) -> Vec<Result<Self>> {
+        let mut scratch = Vec::with_capacity(UT_MAX_STRING_LEN);
+        inputs
+            .iter()
+            .map(|s| {
+                scratch.clear();
+                scratch.extend_from_slice(s.as_bytes());
+                scratch.push(0);
+                let c_str = CStr::from_bytes_with_nul(&scratch)
+                    .map_err(|_| {
+                        Error::new(UT_ERR_INVALID_CSTRING).at(s.bytes().position(|b| b == 0))
+                    })?;
+                Timestamp::parse_cstr(c_str)
+            })
+            .collect()
+    }
+
+    /// Parse strictly like `parse`, also accepting a leap second at `23:59:60`.
+    ///
+    /// The leap second is folded into the first second of the next day, as
+    /// POSIX time does, so `2016-12-31T23:59:60.5Z` equals
+    /// `2017-01-01T00:00:00.5Z`. A `:60` second at any other time of day is
+    /// still rejected.
+    #[cfg(feature = "alloc")]
+    pub fn parse_allow_leap_second(s: &str) -> Result<Self> {
+        match s.get(11..19) {
+            Some("23:59:60") => {
+                let ts = Timestamp::parse(&format!("{}59{}", &s[..17], &s[19..]))?;
+                ts.checked_add_nanos(NANOS_PER_SECOND)
+            }
+            _ => Timestamp::parse(s),
+        }
+    }
+
+    /// Parse a NUL-terminated ISO-8601 string (strict) without copying or allocating.
+    ///
+    /// Accepts and rejects exactly what `parse` does for the same content.
+    pub fn parse_cstr(c_str: &CStr) -> Result<Self> {
+        let mut ts = ut_timestamp_t { nanos: 0 };
+        let err = unsafe { ut_parse_strict(c_str.as_ptr(), &mut ts) };
+        if err != UT_OK {
+            return Err(Error::new(err).at(strict_error_position(c_str.to_bytes())));
+        }
+        Ok(Timestamp { inner: ts })
+    }
+
+    /// Parse ISO-8601 string (lenient).
+    #[cfg(feature = "alloc")]
+    pub fn parse_lenient(s: &str) -> Result<Self> {
+        let c_str = CString::new(s).map_err(|_| Error::new(UT_ERR_INVALID_CSTRING))?;
+        let mut ts = ut_timestamp_t { nanos: 0 };
+        let err = unsafe { ut_parse_lenient(c_str.as_ptr(), &mut ts) };
+        if err != UT_OK {
+            return Err(Error::new(err));
+        }
+        Ok(Timestamp { inner: ts })
+    }
+
+    /// Parse an RFC 3339 string with a `Z` or numeric `±HH:MM` offset, converting to UTC.
+    ///
+    /// The date and time must otherwise follow the strict grammar, so
+    /// `2024-12-14T14:00:00+02:00` equals `2024-12-14T12:00:00Z`.
+    #[cfg(feature = "alloc")]
+    pub fn parse_rfc3339(s: &str) -> Result<Self> {
+        Timestamp::parse_with_offset(s).map(|(ts, _)| ts)
+    }
+
+    /// Parse like `parse_rfc3339`, also returning the source offset in minutes
+    /// east of UTC (0 for `Z`, -480 for `-08:00`).
+    ///
+    /// The offset is a signed integer because `Duration` cannot be negative.
+    #[cfg(feature = "alloc")]
+    pub fn parse_with_offset(s: &str) -> Result<(Self, i32)> {
+        if s.ends_with('Z') {
+            return Timestamp::parse(s).map(|ts| (ts, 0));
+        }
+        if !s.is_ascii() || s.len() < 6 {
+            return Err(Error::new(UT_ERR_INVALID_FORMAT));
+        }
+        let (local, zone) = s.split_at(s.len() - 6);
+        let minutes = parse_numeric_offset(zone)?;
+        let ts = Timestamp::parse(&format!("{}Z", local))?;
+        Ok((ts.checked_sub_nanos(minutes as i64 * 60 * NANOS_PER_SECOND)?, minutes))
+    }
+
+    /// Parse a strict ISO 8601 string whose zone is `Z`, a numeric `±HH:MM`
+    /// offset, or absent (taken as UTC).
+    ///
+    /// Unlike `parse_lenient`, everything before the zone must follow the
+    /// strict grammar; only the zone designator is optional.
+    #[cfg(feature = "alloc")]
+    pub fn parse_flexible(s: &str) -> Result<Self> {
+        let b = s.as_bytes();
+        let has_offset = b.len() >= 25 && matches!(b[b.len() - 6], b'+' | b'-');
+        if s.ends_with('Z') || has_offset {
+            return Timestamp::parse_rfc3339(s);
+        }
+        Timestamp::parse(&format!("{}Z", s))
+    }
+
+    /// Parse `s` with each of `formats` in order, returning the first success.
+    ///
+    /// Only the listed shapes are accepted, which keeps the surface explicit
+    /// for untrusted input. If none match, the error from the last format is
+    /// returned (or an invalid-format error for an empty list).
+    #[cfg(feature = "alloc")]
+    pub fn parse_with_formats(s: &str, formats: &[Format]) -> Result<Self> {
+        let mut last = Error::new(UT_ERR_INVALID_FORMAT);
+        for format in formats {
+            let parsed = match format {
+                Format::Iso8601 => Timestamp::parse(s),
+                Format::Rfc3339 => Timestamp::parse_rfc3339(s),
+                Format::Rfc2822 => Timestamp::parse_rfc2822(s),
+                Format::Iso8601Basic => Timestamp::parse_basic(s),
+                Format::DateOnly => Timestamp::parse_date(s),
+                Format::UnixSeconds => Timestamp::parse_unix(s, Unit::Seconds),
+                Format::UnixMillis => Timestamp::parse_unix(s, Unit::Millis),
+            };
+            match parsed {
+                Ok(ts) => return Ok(ts),
+                Err(e) => last = e,
+            }
+        }
+        Err(last)
+    }
+
+    /// Parse a calendar date, `YYYY-MM-DD`, as midnight UTC.
+    ///
+    /// Anything after the date, such as a time or offset, is rejected.
+    pub fn parse_date(s: &str) -> Result<Self> {
+        let invalid = || Error::new(UT_ERR_INVALID_FORMAT);
+        let b = s.as_bytes();
+        if !s.is_ascii() || b.len() != 10 || b[4] != b'-' || b[7] != b'-' {
+            return Err(invalid());
+        }
+        let year = parse_digits(&s[..4]).ok_or_else(invalid)? as i32;
+        let month = parse_digits(&s[5..7]).ok_or_else(invalid)?;
+        let day = parse_digits(&s[8..]).ok_or_else(invalid)?;
+        Timestamp::from_ymd_hms(year, month, day, 0, 0, 0, 0)
+    }
+
+    /// Parse ISO 8601 basic format, `YYYYMMDDTHHMMSS[.fffffffff]Z`.
+    ///
+    /// This is the inverse of `format_basic`: the fields and fraction follow
+    /// the strict extended grammar with the `-` and `:` separators removed.
+    pub fn parse_basic(s: &str) -> Result<Self> {
+        let invalid = || Error::new(UT_ERR_INVALID_FORMAT);
+        let b = s.as_bytes();
+        if !s.is_ascii() || b.len() < 16 || b[8] != b'T' || b[b.len() - 1] != b'Z' {
+            return Err(invalid());
+        }
+        let field = |range: core::ops::Range<usize>| parse_digits(&s[range]).ok_or_else(invalid);
+        let (year, month, day) = (field(0..4)? as i32, field(4..6)?, field(6..8)?);
+        let (hour, minute, second) = (field(9..11)?, field(11..13)?, field(13..15)?);
+        let nanos = match &s[15..s.len() - 1] {
+            "" => 0,
+            fraction => {
+                let digits = fraction.strip_prefix('.').ok_or_else(invalid)?;
+                if digits.len() > 9 {
+                    return Err(Error::new(UT_ERR_FRACTION_TOO_LONG));
+                }
+                parse_digits(digits).ok_or_else(invalid)? * 10u32.pow(9 - digits.len() as u32)
+            }
+        };
+        Timestamp::from_ymd_hms(year, month, day, hour, minute, second, nanos)
+    }
+
+    /// Parse the `YYYY-MM-DD_HH-MM-SS` form produced by `format_filename` as UTC.
+    pub fn parse_filename(s: &str) -> Result<Self> {
+        let invalid = || Error::new(UT_ERR_INVALID_FORMAT);
+        let b = s.as_bytes();
+        if !s.is_ascii() || b.len() != 19 || b[10] != b'_' {
+            return Err(invalid());
+        }
+        if [4, 7, 13, 16].iter().any(|&i| b[i] != b'-') {
+            return Err(invalid());
+        }
+        let field = |range: core::ops::Range<usize>| parse_digits(&s[range]).ok_or_else(invalid);
+        let (year, month, day) = (field(0..4)? as i32, field(5..7)?, field(8..10)?);
+        let (hour, minute, second) = (field(11..13)?, field(14..16)?, field(17..19)?);
+        Timestamp::from_ymd_hms(year, month, day, hour, minute, second, 0)
+    }
+
+    /// Format to ISO-8601 string.
+    #[cfg(feature = "alloc")]
+    pub fn format(&self, include_nanos: bool) -> String {
+        let mut out = String::with_capacity(UT_MAX_STRING_LEN);
+        self.format_into(&mut out, include_nanos);
+        out
+    }
+
+    /// Format to ISO 8601 basic form without separators, e.g. `20241214T120000Z`.
+    ///
+    /// The fraction, if included, matches `format`.
+    #[cfg(feature = "alloc")]
+    pub fn format_basic(&self, include_nanos: bool) -> String {
+        self.format(include_nanos).chars().filter(|&c| c != '-' && c != ':').collect()
+    }
+
+    /// Format for use in file names, e.g. `2024-12-14_12-00-00`.
+    ///
+    /// The output uses only ASCII digits, `-` and `_`, which are valid in
+    /// Windows and Unix paths. The time is UTC and sub-second digits are
+    /// truncated.
+    #[cfg(feature = "alloc")]
+    pub fn format_filename(&self) -> String {
+        let c = self.civil();
+        format!(
+            "{:04}-{:02}-{:02}_{:02}-{:02}-{:02}",
+            c.year, c.month, c.day, c.hour, c.minute, c.second
+        )
+    }
+
+    /// Format the local wall-clock time at `offset_minutes` east of UTC with a
+    /// `±HH:MM` suffix, e.g. `2024-12-14T17:30:00+05:30`.
+    ///
+    /// The instant is unchanged; `parse_with_offset` reads the string back to
+    /// the same instant and offset. UTC is written as `+00:00`. Offsets of a
+    /// full day or more either way give `ErrorKind::OutOfRange`.
+    #[cfg(feature = "alloc")]
+    pub fn format_at_offset(&self, offset_minutes: i32, include_nanos: bool) -> Result<String> {
+        if offset_minutes.unsigned_abs() >= 24 * 60 {
+            return Err(Error::new(UT_ERR_OUT_OF_RANGE));
+        }
+        let shift = offset_minutes as i128 * 60 * NANOS_PER_SECOND as i128;
+        let local = self.as_nanos() as i128 + shift;
+        let (year, month, day) = civil_from_days(local.div_euclid(NANOS_PER_DAY as i128) as i64);
+        // Format the time of day on its own so shifting past MIN/MAX cannot overflow.
+        let time_of_day = Timestamp::from_nanos(local.rem_euclid(NANOS_PER_DAY as i128) as i64);
+        let time = time_of_day.format(include_nanos);
+        let sign = if offset_minutes < 0 { '-' } else { '+' };
+        let abs = offset_minutes.unsigned_abs();
+        Ok(format!(
+            "{:04}-{:02}-{:02}{}{}{:02}:{:02}",
+            year,
+            month,
+            day,
+            &time[10..time.len() - 1],
+            sign,
+            abs / 60,
+            abs % 60
+        ))
+    }
+
+    /// Format as `YYYY-MM-DD` at exactly midnight UTC, or as `format(true)` otherwise.
+    ///
+    /// Only an exactly zero time of day counts; one nanosecond past midnight
+    /// gives the full form.
+    #[cfg(feature = "alloc")]
+    pub fn format_smart(&self) -> String {
+        if self.as_nanos().rem_euclid(NANOS_PER_DAY) != 0 {
+            return self.format(true);
+        }
+        let c = self.civil();
+        format!("{:04}-{:02}-{:02}", c.year, c.month, c.day)
+    }
+
+    /// Format as RFC 3339 with `Z` (`zulu`) or `+00:00` as the UTC designator.
+    ///
+    /// The fraction, if included, matches `format`.
+    #[cfg(feature = "alloc")]
+    pub fn format_rfc3339(&self, zulu: bool, include_nanos: bool) -> String {
+        let mut out = self.format(include_nanos);
+        if !zulu {
+            out.pop();
+            out.push_str("+00:00");
+        }
+        out
+    }
+
+    /// Format to ISO-8601 with exactly 0, 3, 6 or 9 fractional digits.
+    ///
+    /// Digits below `precision` are truncated and the fraction is zero-padded
+    /// to full width. `Precision::Error` keeps all nine digits.
+    #[cfg(feature = "alloc")]
+    pub fn format_with_precision(&self, precision: Precision) -> String {
+        let digits = match precision {
+            Precision::Second => 0,
+            Precision::Millisecond => 3,
+            Precision::Microsecond => 6,
+            Precision::Nanosecond | Precision::Error => 9,
+        };
+        self.format_digits(digits)
+    }
+
+    /// Format to ISO-8601 with `DIGITS` fractional digits fixed at compile time.
+    ///
+    /// `DIGITS` must be 0, 3, 6 or 9; any other count fails to build.
+    /// Digits are truncated and zero-padded as in `format_with_precision`.
+    ///
+    /// ```compile_fail
+    /// # use universal_timestamp::Timestamp;
+    /// let _ = Timestamp::EPOCH.format_fixed::<4>();
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format_fixed<const DIGITS: usize>(&self) -> String {
+        #[allow(clippy::let_unit_value)]
+        let () = FractionDigits::<DIGITS>::VALID;
+        self.format_digits(DIGITS)
+    }
+
+    /// Format with `digits` (0-9) zero-padded, truncated fractional digits.
+    #[cfg(feature = "alloc")]
+    fn format_digits(&self, digits: usize) -> String {
+        let mut raw = [0u8; UT_MAX_STRING_LEN];
+        let len = self.format_raw(&mut raw, false);
+        // Drop the trailing `Z` so the fraction can go before it.
+        let seconds = core::str::from_utf8(&raw[..len - 1]).unwrap_or_default();
+        if digits == 0 {
+            return format!("{}Z", seconds);
+        }
+        let fraction = self.nanosecond() / 10u32.pow(9 - digits as u32);
+        format!("{}.{:0width$}Z", seconds, fraction, width = digits)
+    }
+
+    /// Format to ISO-8601, replacing the contents of `buf` and reusing its capacity.
+    #[cfg(feature = "alloc")]
+    pub fn format_into(&self, buf: &mut String, include_nanos: bool) {
+        let mut raw = [0u8; UT_MAX_STRING_LEN];
+        let len = self.format_raw(&mut raw, include_nanos);
+        buf.clear();
+        buf.push_str(&String::from_utf8_lossy(&raw[..len]));
+    }
+
+    /// Format to ISO-8601 into `buf` without allocating, returning the written prefix.
+    ///
+    /// A buffer of `UT_MAX_STRING_LEN` bytes always fits; a smaller one fails
+    /// with a "Buffer too small" error if the output does not fit.
+    pub fn format_to_slice<'a>(&self, buf: &'a mut [u8], include_nanos: bool) -> Result<&'a str> {
+        let mut raw = [0u8; UT_MAX_STRING_LEN];
+        let len = self.format_raw(&mut raw, include_nanos);
+        let out = buf.get_mut(..len).ok_or_else(|| Error::new(UT_ERR_BUFFER_TOO_SMALL))?;
+        out.copy_from_slice(&raw[..len]);
+        // ut_format only emits ASCII.
+        Ok(core::str::from_utf8(out).unwrap_or_default())
+    }
+
+    /// Run `ut_format` into a stack buffer, returning the string length.
+    fn format_raw(&self, raw: &mut [u8; UT_MAX_STRING_LEN], include_nanos: bool) -> usize {
+        unsafe {
+            ut_format(self.inner, raw.as_mut_ptr() as *mut c_char, raw.len(), include_nanos);
+        }
+        raw.iter().position(|&c| c == 0).unwrap_or(raw.len())
+    }
+
+    /// Whole UTC days since 1970-01-01, rounded toward negative infinity.
+    fn unix_days(&self) -> i64 {
+        self.as_nanos().div_euclid(NANOS_PER_DAY)
+    }
+
+    /// Decompose into UTC calendar fields.
+    fn civil(&self) -> Civil {
+        let nanos = self.as_nanos();
+        let (year, month, day) = civil_from_days(self.unix_days());
+        let day_nanos = nanos.rem_euclid(NANOS_PER_DAY);
+        let secs = day_nanos / NANOS_PER_SECOND;
+        Civil {
+            year,
+            month,
+            day,
+            hour: (secs / 3600) as u32,
+            minute: (secs % 3600 / 60) as u32,
+            second: (secs % 60) as u32,
+            nanosecond: (day_nanos % NANOS_PER_SECOND) as u32,
+        }
+    }
+
+    /// Recompose from UTC calendar fields, validating each one.
+    fn from_civil(c: Civil) -> Result<Self> {
+        Timestamp::from_ymd_hms(c.year, c.month, c.day, c.hour, c.minute, c.second, c.nanosecond)
+    }
+
+    /// Same instant with the UTC nanosecond-of-second replaced (0-999,999,999).
+    pub fn with_nanosecond(&self, nanosecond: u32) -> Result<Self> {
+        Timestamp::from_civil(Civil { nanosecond, ..self.civil() })
+    }
+
+    /// Same instant with the UTC second replaced (0-59).
+    pub fn with_second(&self, second: u32) -> Result<Self> {
+        Timestamp::from_civil(Civil { second, ..self.civil() })
+    }
+
+    /// Same instant with the UTC minute replaced (0-59).
+    pub fn with_minute(&self, minute: u32) -> Result<Self> {
+        Timestamp::from_civil(Civil { minute, ..self.civil() })
+    }
+
+    /// Same instant with the UTC hour replaced (0-23).
+    pub fn with_hour(&self, hour: u32) -> Result<Self> {
+        Timestamp::from_civil(Civil { hour, ..self.civil() })
+    }
+
+    /// Shift by whole calendar months, keeping the time of day.
+    ///
+    /// The day is clamped to the end of the target month, so January 31 plus
+    /// one month is February 28 or 29. Fails if the result leaves the `i64` range.
+    pub fn add_months(&self, months: i32) -> Result<Timestamp> {
+        self.shift_months(months as i64)
+    }
+
+    /// Shift by whole calendar years, clamping February 29 to February 28.
+    pub fn add_years(&self, years: i32) -> Result<Timestamp> {
+        self.shift_months(years as i64 * 12)
+    }
+
+    fn shift_months(&self, months: i64) -> Result<Timestamp> {
+        let overflow = || Error::new(UT_ERR_OVERFLOW);
+        let c = self.civil();
+        let total = c.year as i64 * 12 + (c.month as i64 - 1) + months;
+        let year = i32::try_from(total.div_euclid(12)).map_err(|_| overflow())?;
+        let month = total.rem_euclid(12) as u32 + 1;
+        let day = c.day.min(days_in_month(year, month));
+        Timestamp::from_civil(Civil { year, month, day, ..c }).map_err(|_| overflow())
+    }
+
+    /// UTC midnight at or before this instant.
+    ///
+    /// Saturates to `Timestamp::MIN` on the partial first day of the range.
+    pub fn start_of_day(&self) -> Timestamp {
+        Timestamp::midnight(self.unix_days())
+    }
+
+    /// Last nanosecond of this instant's UTC day, i.e. one before the next midnight.
+    ///
+    /// Inclusive, so the result is on the same date. Saturates to
+    /// `Timestamp::MAX` on the partial last day of the range.
+    pub fn end_of_day(&self) -> Timestamp {
+        let next_midnight = (self.unix_days() as i128 + 1) * NANOS_PER_DAY as i128;
+        Timestamp::from_nanos(saturate_nanos(next_midnight - 1))
+    }
+
+    /// First instant (the 1st, 00:00:00Z) of this instant's UTC month.
+    ///
+    /// Saturates to `Timestamp::MIN` in the partial first month of the range.
+    pub fn start_of_month(&self) -> Timestamp {
+        let c = self.civil();
+        Timestamp::midnight(days_from_civil(c.year, c.month, 1))
+    }
+
+    /// First instant (January 1, 00:00:00Z) of this instant's UTC year.
+    ///
+    /// Saturates to `Timestamp::MIN` in the partial first year of the range.
+    pub fn start_of_year(&self) -> Timestamp {
+        Timestamp::midnight(days_from_civil(self.year(), 1, 1))
+    }
+
+    /// Midnight UTC starting day `days` since the epoch, saturating at the range limits.
+    fn midnight(days: i64) -> Timestamp {
+        Timestamp::from_nanos(saturate_nanos(days as i128 * NANOS_PER_DAY as i128))
+    }
+
+    /// UTC calendar year.
+    pub fn year(&self) -> i32 {
+        self.civil().year
+    }
+
+    /// UTC month (1-12).
+    pub fn month(&self) -> u32 {
+        self.civil().month
+    }
+
+    /// UTC day of the month (1-31).
+    pub fn day(&self) -> u32 {
+        self.civil().day
+    }
+
+    /// UTC hour (0-23).
+    pub fn hour(&self) -> u32 {
+        self.civil().hour
+    }
+
+    /// UTC minute (0-59).
+    pub fn minute(&self) -> u32 {
+        self.civil().minute
+    }
+
+    /// UTC second (0-59).
+    pub fn second(&self) -> u32 {
+        self.civil().second
+    }
+
+    /// Nanoseconds within the second (0-999,999,999).
+    pub fn nanosecond(&self) -> u32 {
+        self.civil().nanosecond
+    }
+
+    /// Nanoseconds within the second, in `[0, 1_000_000_000)`.
+    ///
+    /// Same as `nanosecond`, named after `Duration::subsec_nanos`. The value is
+    /// the Euclidean remainder, so it stays non-negative before the epoch:
+    /// -0.25s has 750,000,000.
+    pub fn subsec_nanos(&self) -> u32 {
+        self.as_nanos().rem_euclid(NANOS_PER_SECOND) as u32
+    }
+
+    /// Whole microseconds within the second, in `[0, 1_000_000)`.
+    pub fn subsec_micros(&self) -> u32 {
+        self.subsec_nanos() / 1_000
+    }
+
+    /// Whole milliseconds within the second, in `[0, 1_000)`.
+    pub fn subsec_millis(&self) -> u32 {
+        self.subsec_nanos() / 1_000_000
+    }
+
+    /// Calendar quarter (1-4) of the UTC month.
+    pub fn quarter(&self) -> u32 {
+        (self.month() - 1) / 3 + 1
+    }
+
+    /// Half of the year (1 or 2) of the UTC month.
+    pub fn half(&self) -> u32 {
+        (self.month() - 1) / 6 + 1
+    }
+
+    /// Whether the UTC calendar year is a Gregorian leap year.
+    pub fn is_in_leap_year(&self) -> bool {
+        is_leap_year(self.year())
+    }
+
+    /// UTC day of the year (1-366).
+    pub fn day_of_year(&self) -> u32 {
+        self.ordinal_date().1
+    }
+
+    /// UTC ordinal date as `(year, day_of_year)`.
+    pub fn ordinal_date(&self) -> (i32, u32) {
+        let year = self.year();
+        let ordinal = self.unix_days() - days_from_civil(year, 1, 1) + 1;
+        (year, ordinal as u32)
+    }
+
+    /// Format as an ISO 8601 ordinal date, e.g. `2024-349`.
+    #[cfg(feature = "alloc")]
+    pub fn format_ordinal(&self) -> String {
+        let (year, day) = self.ordinal_date();
+        format!("{:04}-{:03}", year, day)
+    }
+
+    /// Parse an ISO 8601 ordinal date such as `2024-349` as midnight UTC.
+    ///
+    /// The day must be three digits, 001 through 365 (366 in leap years).
+    pub fn parse_ordinal(s: &str) -> Result<Self> {
+        let invalid = || Error::new(UT_ERR_INVALID_FORMAT);
+        let b = s.as_bytes();
+        if !s.is_ascii() || b.len() != 8 || b[4] != b'-' {
+            return Err(invalid());
+        }
+        let year = parse_digits(&s[..4]).ok_or_else(invalid)? as i32;
+        let day = parse_digits(&s[5..]).ok_or_else(invalid)?;
+        let days_in_year = if is_leap_year(year) { 366 } else { 365 };
+        if day == 0 || day > days_in_year {
+            return Err(Error::new(UT_ERR_INVALID_DATE));
+        }
+        let jan1 = Timestamp::from_ymd_hms(year, 1, 1, 0, 0, 0, 0)?;
+        jan1.checked_add_nanos((day as i64 - 1) * NANOS_PER_DAY)
+    }
+
+    /// UTC day of the week.
+    pub fn weekday(&self) -> Weekday {
+        Weekday::from_monday_index(weekday_index(self.unix_days()))
+    }
+
+    /// The first `day` at or after this instant, keeping the UTC time of day.
+    ///
+    /// Returns `self` unchanged if it already falls on `day`. Saturates at `MAX`.
+    pub fn next_weekday(&self, day: Weekday) -> Timestamp {
+        let current = weekday_index(self.unix_days());
+        let ahead = (day.number_from_monday() - 1 + 7 - current) % 7;
+        self.saturating_add_nanos(ahead as i64 * NANOS_PER_DAY)
+    }
+
+    /// The last `day` at or before this instant, keeping the UTC time of day.
+    ///
+    /// Returns `self` unchanged if it already falls on `day`. Saturates at `MIN`.
+    pub fn previous_weekday(&self, day: Weekday) -> Timestamp {
+        let current = weekday_index(self.unix_days());
+        let behind = (current + 7 - (day.number_from_monday() - 1)) % 7;
+        self.saturating_add_nanos(-(behind as i64) * NANOS_PER_DAY)
+    }
+
+    /// Move by `days` UTC business days (Monday to Friday), keeping the time of day.
+    ///
+    /// Friday plus one is the following Monday and Monday minus one the
+    /// previous Friday. From a weekend, counting starts at the adjacent
+    /// business day, so Saturday plus one is Monday. Zero returns `self`
+    /// and results saturate at `MIN`/`MAX`.
+    pub fn add_business_days(&self, days: i64) -> Timestamp {
+        if days == 0 {
+            return *self;
+        }
+        let start = self.unix_days() as i128;
+        // From a weekend, count from the Friday before or the Monday after.
+        let (from, weekday) = match weekday_index(self.unix_days()) as i128 {
+            weekday @ 5..=6 if days > 0 => (start + 4 - weekday, 4),
+            weekday @ 5..=6 => (start + 7 - weekday, 0),
+            weekday => (start, weekday),
+        };
+        let (weeks, rest) = ((days as i128).abs() / 5, (days as i128).abs() % 5);
+        let target = if days > 0 {
+            from + weeks * 7 + rest + if weekday + rest > 4 { 2 } else { 0 }
+        } else {
+            from - weeks * 7 - rest - if weekday < rest { 2 } else { 0 }
+        };
+        let shift = (target - start) * NANOS_PER_DAY as i128;
+        Timestamp::from_nanos(saturate_nanos(self.as_nanos() as i128 + shift))
+    }
+
+    /// UTC ISO 8601 week date as `(week_year, week, weekday)`, Monday = 1.
+    ///
+    /// Computed with floored day arithmetic, so instants before the epoch
+    /// land in the right week at any time of day.
+    pub fn to_iso_week(&self) -> (i32, i32, i32) {
+        let days = self.unix_days();
+        let weekday = weekday_index(days) as i64;
+        // The ISO week belongs to the year containing its Thursday.
+        let thursday = days - weekday + 3;
+        let (year, _, _) = civil_from_days(thursday);
+        let week = (thursday - days_from_civil(year, 1, 1)) / 7 + 1;
+        (year, week as i32, weekday as i32 + 1)
+    }
+    
+    /// Format as an ISO 8601 week date, e.g. `2024-W50-6`.
+    ///
+    /// Uses the ISO week-numbering year, which differs from the calendar year
+    /// for a few days around New Year.
+    #[cfg(feature = "alloc")]
+    pub fn format_iso_week(&self) -> String {
+        let (year, week, day) = self.to_iso_week();
+        format!("{:04}-W{:02}-{}", year, week, day)
+    }
+
+    /// Parse an ISO 8601 week date such as `2024-W50-6`.
+    ///
+    /// Yields midnight UTC of that day. A strict time suffix (`T12:00:00Z`)
+    /// may follow, in which case that time of day is used instead.
+    #[cfg(feature = "alloc")]
+    pub fn parse_iso_week(s: &str) -> Result<Self> {
+        let invalid = || Error::new(UT_ERR_INVALID_FORMAT);
+        let (date, time) = s.split_at(s.find('T').unwrap_or(s.len()));
+        let b = date.as_bytes();
+        if !date.is_ascii() || b.len() != 10 || b[4] != b'-' || b[5] != b'W' || b[8] != b'-' {
+            return Err(invalid());
+        }
+        let year = parse_digits(&date[..4]).ok_or_else(invalid)? as i32;
+        let week = parse_digits(&date[6..8]).ok_or_else(invalid)?;
+        let day = parse_digits(&date[9..]).ok_or_else(invalid)?;
+        if week == 0 || week > iso_weeks_in_year(year) || day == 0 || day > 7 {
+            return Err(Error::new(UT_ERR_INVALID_DATE));
+        }
+
+        let jan4 = days_from_civil(year, 1, 4);
+        let week1_monday = jan4 - weekday_index(jan4) as i64;
+        let (y, m, d) = civil_from_days(week1_monday + (week as i64 - 1) * 7 + (day as i64 - 1));
+        if time.is_empty() {
+            Timestamp::from_ymd_hms(y, m, d, 0, 0, 0, 0)
+        } else {
+            Timestamp::parse(&format!("{:04}-{:02}-{:02}{}", y, m, d, time))
+        }
+    }
+
+    /// Format as RFC 2822, e.g. `Sat, 14 Dec 2024 12:00:00 +0000`. Sub-second digits are dropped.
+    #[cfg(feature = "alloc")]
+    pub fn format_rfc2822(&self) -> String {
+        let c = self.civil();
+        format!(
+            "{}, {:02} {} {:04} {:02}:{:02}:{:02} +0000",
+            WEEKDAY_ABBR[weekday_index(self.unix_days()) as usize],
+            c.day,
+            MONTH_ABBR[c.month as usize - 1],
+            c.year,
+            c.hour,
+            c.minute,
+            c.second
+        )
+    }
+
+    /// Parse an RFC 2822 date, normalizing any offset to UTC.
+    ///
+    /// The weekday is optional, and the obsolete two-digit years (`24` is 2024,
+    /// `99` is 1999) and named zones (`GMT`, `EST`, ...) are accepted.
+    pub fn parse_rfc2822(s: &str) -> Result<Self> {
+        let invalid = || Error::new(UT_ERR_INVALID_FORMAT);
+        let rest = match s.split_once(',') {
+            Some((name, rest)) => {
+                if !WEEKDAY_ABBR.iter().any(|n| n.eq_ignore_ascii_case(name.trim())) {
+                    return Err(invalid());
+                }
+                rest
+            }
+            None => s,
+        };
+        let mut parts = rest.split_whitespace();
+        let mut next = || parts.next().ok_or_else(invalid);
+
+        let day_tok = next()?;
+        let day = parse_digits(day_tok).filter(|_| day_tok.len() <= 2).ok_or_else(invalid)?;
+        let month_tok = next()?;
+        let month = MONTH_ABBR
+            .iter()
+            .position(|n| n.eq_ignore_ascii_case(month_tok))
+            .ok_or_else(invalid)? as u32
+            + 1;
+        let year_tok = next()?;
+        let year = parse_digits(year_tok).ok_or_else(invalid)? as i32;
+        let year = match year_tok.len() {
+            2 if year < 50 => 2000 + year,
+            2 | 3 => 1900 + year,
+            4 => year,
+            _ => return Err(invalid()),
+        };
+
+        let time_tok = next()?;
+        let mut fields = [0u32; 3];
+        let mut count = 0;
+        for field in time_tok.split(':') {
+            if count == 3 || field.len() != 2 {
+                return Err(invalid());
+            }
+            fields[count] = parse_digits(field).ok_or_else(invalid)?;
+            count += 1;
+        }
+        if count < 2 {
+            return Err(invalid());
+        }
+
+        let offset = parse_rfc2822_zone(next()?).ok_or_else(invalid)?;
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        let local = Timestamp::from_ymd_hms(year, month, day, fields[0], fields[1], fields[2], 0)?;
+        local.checked_sub_nanos(offset as i64 * 60 * NANOS_PER_SECOND)
+    }
+
+    /// Coarse English phrase for this instant relative to `reference`, such as
+    /// `3 minutes ago` or `in 2 hours`.
+    ///
+    /// Gaps under 45 seconds are `just now`; under 45 minutes count minutes;
+    /// under 22 hours count hours; anything longer counts days. Counts are
+    /// rounded to the nearest unit and never below one.
+    #[cfg(feature = "alloc")]
+    pub fn humanize_relative_to(&self, reference: Timestamp) -> String {
+        let secs = self.as_nanos().abs_diff(reference.as_nanos()) / NANOS_PER_SECOND as u64;
+        let (count, unit) = match secs {
+            0..=44 => return "just now".to_string(),
+            45..=2_699 => ((secs + 30) / 60, "minute"),
+            2_700..=79_199 => ((secs + 1_800) / 3_600, "hour"),
+            _ => ((secs + 43_200) / 86_400, "day"),
+        };
+        let count = count.max(1);
+        let plural = if count == 1 { "" } else { "s" };
+        if *self < reference {
+            format!("{} {}{} ago", count, unit, plural)
+        } else {
+            format!("in {} {}{}", count, unit, plural)
+        }
+    }
+
+    /// UTC offset in minutes east of UTC that `tz` observes at this instant.
+    ///
+    /// The C library has no zone database, so only fixed offsets are known:
+    /// `UTC`, `GMT`, `Z`, `Etc/UTC`, `Etc/GMT`, and `±HH:MM` optionally
+    /// prefixed by `UTC` or `GMT` (`UTC+05:30`). The result therefore never
+    /// depends on the instant. Other names, including IANA regions such as
+    /// `Europe/Berlin`, fail with an unknown-time-zone error.
+    pub fn utc_offset_in(&self, tz: &str) -> Result<i32> {
+        match tz {
+            "UTC" | "GMT" | "Z" | "Etc/UTC" | "Etc/GMT" => return Ok(0),
+            _ => {}
+        }
+        let numeric = tz.strip_prefix("UTC").or_else(|| tz.strip_prefix("GMT")).unwrap_or(tz);
+        if numeric.starts_with('+') || numeric.starts_with('-') {
+            return parse_numeric_offset(numeric);
+        }
+        Err(Error::new(UT_ERR_UNKNOWN_TIME_ZONE))
+    }
+
+    /// Japanese era and year within that era. Fails for dates before Meiji.
+    pub fn to_japanese_era(&self) -> Result<(JapaneseEra, i32)> {
+        let mut era = 0;
+        let mut year = 0;
+        let err = unsafe { ut_to_japanese_era(self.inner, &mut era, &mut year) };
+        if err != UT_OK {
+            return Err(Error::new(err));
+        }
+        Ok((JapaneseEra::from_code(era), year))
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut raw = [0u8; UT_MAX_STRING_LEN];
+        let len = self.format_raw(&mut raw, true);
+        f.write_str(core::str::from_utf8(&raw[..len]).unwrap_or_default())
+    }
+}
+
+/// Prints `Timestamp(2024-12-14T12:00:00.000000000Z)`.
+///
+/// Unlike `Display`, the fraction always has nine digits so that values in
+/// assertion failures line up. The raw value is available from `as_nanos`.
+impl fmt::Debug for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut raw = [0u8; UT_MAX_STRING_LEN];
+        let len = self.format_raw(&mut raw, false);
+        let seconds = core::str::from_utf8(&raw[..len - 1]).unwrap_or_default();
+        write!(f, "Timestamp({}.{:09}Z)", seconds, self.nanosecond())
+    }
+}
+
+/// Checked alternative to `Timestamp::from_nanos`.
+///
+/// Accepts nanoseconds from -9,223,372,036,000,000,000 (1677-09-21T00:12:44Z)
+/// up to `i64::MAX` (2262-04-11T23:47:16.854775807Z): the range in which
+/// formatting and strict parsing round-trip. Earlier values fail with an
+/// out-of-range error.
+impl TryFrom<i64> for Timestamp {
+    type Error = Error;
+
+    fn try_from(nanos: i64) -> Result<Self> {
+        if nanos < MIN_ROUND_TRIP_NANOS {
+            return Err(Error::new(UT_ERR_OUT_OF_RANGE));
+        }
+        Ok(Timestamp::from_nanos(nanos))
+    }
+}
+
+/// The Unix epoch, built without calling into the C library.
+impl Default for Timestamp {
+    fn default() -> Self {
+        Timestamp::EPOCH
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for Timestamp {
+    type Err = Error;
+
+    /// Parse ISO-8601 string (strict). Use `Timestamp::parse_lenient` for the relaxed grammar.
+    fn from_str(s: &str) -> Result<Self> {
+        Timestamp::parse(s)
+    }
+}
+
+/// Compile-time check of the `format_fixed` digit count.
+#[cfg(feature = "alloc")]
+struct FractionDigits<const DIGITS: usize>;
+
+#[cfg(feature = "alloc")]
+impl<const DIGITS: usize> FractionDigits<DIGITS> {
+    const VALID: () = assert!(
+        DIGITS == 0 || DIGITS == 3 || DIGITS == 6 || DIGITS == 9,
+        "fractional digits must be 0, 3, 6 or 9"
+    );
+}
+
+// `from_nanos_slice` and `as_nanos_slice` rely on `Timestamp` matching `i64`.
+const _: () = assert!(
+    core::mem::size_of::<Timestamp>() == core::mem::size_of::<i64>()
+        && core::mem::align_of::<Timestamp>() == core::mem::align_of::<i64>()
+);
+
+/// Clamp a wide nanosecond value into the `i64` range of a timestamp.
+fn saturate_nanos(nanos: i128) -> i64 {
+    nanos.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+impl Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    /// Offset forward by `rhs`, saturating at `i64::MAX` nanoseconds.
+    fn add(self, rhs: Duration) -> Timestamp {
+        Timestamp::from_nanos(saturate_nanos(self.as_nanos() as i128 + rhs.as_nanos() as i128))
+    }
+}
+
+impl AddAssign<Duration> for Timestamp {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    /// Offset backward by `rhs`, saturating at `i64::MIN` nanoseconds.
+    fn sub(self, rhs: Duration) -> Timestamp {
+        Timestamp::from_nanos(saturate_nanos(self.as_nanos() as i128 - rhs.as_nanos() as i128))
+    }
+}
+
+impl SubAssign<Duration> for Timestamp {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub<Timestamp> for Timestamp {
+    type Output = Duration;
+
+    /// Absolute gap between two timestamps, regardless of order.
+    fn sub(self, rhs: Timestamp) -> Duration {
+        Duration::from_nanos(self.as_nanos().abs_diff(rhs.as_nanos()))
+    }
+}
+
+/// A reading of a monotonic clock, for measuring intervals.
+///
+/// With `std` this wraps `std::time::Instant`, a steady clock unaffected by
+/// changes to the system time. Without `std` it falls back to the C
+/// `ut_now_monotonic`, the wall clock bumped by a nanosecond whenever it
+/// would not advance, so there a stepped system clock shows up in `elapsed`.
+/// Readings never go backwards in either case, and have no formatting or
+/// conversion to `Timestamp`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Monotonic {
+    #[cfg(feature = "std")]
+    instant: Instant,
+    #[cfg(not(feature = "std"))]
+    nanos: i64,
+}
+
+impl Monotonic {
+    /// Read the clock.
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        Monotonic { instant: Instant::now() }
+    }
+
+    /// Read the clock.
+    #[cfg(not(feature = "std"))]
+    pub fn now() -> Self {
+        Monotonic { nanos: unsafe { ut_now_monotonic() }.nanos }
+    }
+
+    /// Time elapsed since this reading.
+    pub fn elapsed(&self) -> Duration {
+        Monotonic::now().duration_since(*self)
+    }
+
+    /// Time from `earlier` to this reading, or zero if `earlier` is later.
+    #[cfg(feature = "std")]
+    pub fn duration_since(&self, earlier: Monotonic) -> Duration {
+        self.instant.saturating_duration_since(earlier.instant)
+    }
+
+    /// Time from `earlier` to this reading, or zero if `earlier` is later.
+    #[cfg(not(feature = "std"))]
+    pub fn duration_since(&self, earlier: Monotonic) -> Duration {
+        Duration::from_nanos(self.nanos.saturating_sub(earlier.nanos).max(0) as u64)
+    }
+}
+
+/// A cached wall-clock reading for hot paths such as logging.
+///
+/// `get` returns the value stored by the last `refresh` (or `new`) without
+/// reading the clock, so staleness is bounded by how often the caller
+/// refreshes, e.g. from a timer every few milliseconds. Both take `&self`,
+/// so one clock can be shared between threads.
+///
+/// Only available on targets with 64-bit atomics, which rules out e.g.
+/// `thumbv6m`, `thumbv7m` and `riscv32imc`.
+#[cfg(target_has_atomic = "64")]
+#[derive(Debug)]
+pub struct CoarseClock {
+    nanos: AtomicI64,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl CoarseClock {
+    /// Create a clock holding the current time.
+    pub fn new() -> Self {
+        CoarseClock { nanos: AtomicI64::new(Timestamp::now().as_nanos()) }
+    }
+
+    /// The cached reading.
+    pub fn get(&self) -> Timestamp {
+        Timestamp::from_nanos(self.nanos.load(Ordering::Relaxed))
+    }
+
+    /// Read `Timestamp::now()` into the cache and return it.
+    pub fn refresh(&self) -> Timestamp {
+        let now = Timestamp::now();
+        self.nanos.store(now.as_nanos(), Ordering::Relaxed);
+        now
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Default for CoarseClock {
+    fn default() -> Self {
+        CoarseClock::new()
+    }
+}
+
+/// Iterator over `[start, end)` in fixed steps, created by `Timestamp::range`.
+#[derive(Debug, Clone)]
+pub struct TimestampRange {
+    next: Option<Timestamp>,
+    end: Timestamp,
+    step: i128,
+}
+
+impl Iterator for TimestampRange {
+    type Item = Timestamp;
+
+    fn next(&mut self) -> Option<Timestamp> {
+        let current = self.next.filter(|ts| *ts < self.end)?;
+        // Stop rather than wrap once the next step leaves the i64 range.
+        self.next = i64::try_from(current.as_nanos() as i128 + self.step)
+            .ok()
+            .map(Timestamp::from_nanos);
+        Some(current)
+    }
+}
+
+/// Iterator over `range`'s items in reverse, created by `Timestamp::range_rev`.
+#[derive(Debug, Clone)]
+pub struct TimestampRangeRev {
+    next: Option<Timestamp>,
+    start: Timestamp,
+    step: i128,
+}
+
+impl Iterator for TimestampRangeRev {
+    type Item = Timestamp;
+
+    fn next(&mut self) -> Option<Timestamp> {
+        let current = self.next.filter(|ts| *ts >= self.start)?;
+        // Stop rather than wrap once the next step leaves the i64 range.
+        self.next = i64::try_from(current.as_nanos() as i128 - self.step)
+            .ok()
+            .map(Timestamp::from_nanos);
+        Some(current)
+    }
+}
+
+/// Calendar-unit span returned by `Timestamp::calendar_diff`.
+///
+/// `months` is below 12 and `days` below 31 in magnitude; all fields share a sign.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CalendarDelta {
+    pub years: i32,
+    pub months: i32,
+    pub days: i32,
+}
+
+/// Incremental construction from UTC calendar fields.
+///
+/// Fields that are not set default to the epoch, 1970-01-01T00:00:00Z, so
+/// setting only the date yields midnight. `build` validates like
+/// `Timestamp::from_ymd_hms`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TimestampBuilder {
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanosecond: u32,
+}
+
+impl TimestampBuilder {
+    /// Start from 1970-01-01T00:00:00Z.
+    pub fn new() -> Self {
+        TimestampBuilder {
+            year: 1970,
+            month: 1,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+        }
+    }
+
+    /// Proleptic Gregorian year, 1677 to 2262 (the `i64` range); defaults to 1970.
+    pub fn year(mut self, year: i32) -> Self {
+        self.year = year;
+        self
+    }
+
+    /// Month, 1 to 12; defaults to 1.
+    pub fn month(mut self, month: u32) -> Self {
+        self.month = month;
+        self
+    }
+
+    /// Day of the month, 1 to the length of the month; defaults to 1.
+    pub fn day(mut self, day: u32) -> Self {
+        self.day = day;
+        self
+    }
+
+    /// Hour, 0 to 23; defaults to 0.
+    pub fn hour(mut self, hour: u32) -> Self {
+        self.hour = hour;
+        self
+    }
+
+    /// Minute, 0 to 59; defaults to 0.
+    pub fn minute(mut self, minute: u32) -> Self {
+        self.minute = minute;
+        self
+    }
+
+    /// Second, 0 to 59 (no leap seconds); defaults to 0.
+    pub fn second(mut self, second: u32) -> Self {
+        self.second = second;
+        self
+    }
+
+    /// Nanosecond within the second, 0 to 999_999_999; defaults to 0.
+    pub fn nanosecond(mut self, nanosecond: u32) -> Self {
+        self.nanosecond = nanosecond;
+        self
+    }
+
+    /// Validate the fields and produce the timestamp.
+    pub fn build(&self) -> Result<Timestamp> {
+        Timestamp::from_ymd_hms(
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanosecond,
+        )
+    }
+}
+
+impl Default for TimestampBuilder {
+    fn default() -> Self {
+        TimestampBuilder::new()
+    }
+}
+
+/// Input shapes accepted by `Timestamp::parse_with_formats`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Format {
+    /// Strict ISO 8601 extended format, as `Timestamp::parse`.
+    Iso8601,
+    /// RFC 3339 with `Z` or a numeric offset, as `Timestamp::parse_rfc3339`.
+    Rfc3339,
+    /// RFC 2822, as `Timestamp::parse_rfc2822`.
+    Rfc2822,
+    /// ISO 8601 basic format, `20241214T120000Z` with an optional fraction.
+    Iso8601Basic,
+    /// A calendar date, `2024-12-14`, as `Timestamp::parse_date`.
+    DateOnly,
+    /// Integer seconds since Unix epoch, optionally negative.
+    UnixSeconds,
+    /// Integer milliseconds since Unix epoch, optionally negative.
+    UnixMillis,
+}
+
+/// Rounding direction for `Timestamp::snap_to_minutes`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SnapMode {
+    /// The boundary at or before the instant.
+    Floor,
+    /// The boundary at or after the instant.
+    Ceil,
+    /// The closer boundary, preferring the later one on a tie.
+    Nearest,
+}
+
+/// Scale of an integer count since the Unix epoch, for `Timestamp::parse_unix`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// Whole seconds, as in `time_t`.
+    Seconds,
+    /// Milliseconds, as from JavaScript's `Date.now()`.
+    Millis,
+    /// Microseconds (10^-6 s).
+    Micros,
+    /// Nanoseconds, the native resolution of `Timestamp`.
+    Nanos,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Precision {
+    Nanosecond = 0,
+    Microsecond = 1,
+    Millisecond = 2,
+    Second = 3,
+    Error = -1,
+}
+
+impl Precision {
+    /// Length of one unit in nanoseconds, or `None` for `Precision::Error`.
+    fn unit_nanos(&self) -> Option<i64> {
+        match self {
+            Precision::Nanosecond => Some(1),
+            Precision::Microsecond => Some(1_000),
+            Precision::Millisecond => Some(1_000_000),
+            Precision::Second => Some(NANOS_PER_SECOND),
+            Precision::Error => None,
+        }
+    }
+}
+
+pub fn get_clock_precision() -> Precision {
+    let p = unsafe { ut_get_clock_precision() };
+    match p {
+        0 => Precision::Nanosecond,
+        1 => Precision::Microsecond,
+        2 => Precision::Millisecond,
+        3 => Precision::Second,
+        _ => Precision::Error,
+    }
+}
+
+/// Best-case granularity of the wall clock, from the detected `Precision`.
+///
+/// Platform-dependent and sampled at call time, like `get_clock_precision`;
+/// `Precision::Error` is reported as one second, the coarsest level.
+pub fn get_clock_resolution() -> Duration {
+    let nanos = get_clock_precision().unit_nanos().unwrap_or(NANOS_PER_SECOND);
+    Duration::from_nanos(nanos as u64)
+}
+
+/// Converts through the Unix epoch; pre-epoch times become negative nanoseconds.
+///
+/// Values beyond the `i64` nanosecond range saturate. On platforms where
+/// `SystemTime` is coarser than a nanosecond, the extra digits are zero.
+#[cfg(feature = "std")]
+impl From<SystemTime> for Timestamp {
+    fn from(t: SystemTime) -> Self {
+        let nanos = match t.duration_since(UNIX_EPOCH) {
+            Ok(d) => d.as_nanos() as i128,
+            Err(e) => -(e.duration().as_nanos() as i128),
+        };
+        Timestamp::from_nanos(saturate_nanos(nanos))
+    }
+}
+
+/// Fails if the instant is not representable as a `SystemTime` on this platform.
+#[cfg(feature = "std")]
+impl TryFrom<Timestamp> for SystemTime {
+    type Error = Error;
+
+    fn try_from(ts: Timestamp) -> Result<Self> {
+        let nanos = ts.as_nanos();
+        let magnitude = Duration::from_nanos(nanos.unsigned_abs());
+        let t = if nanos >= 0 {
+            UNIX_EPOCH.checked_add(magnitude)
+        } else {
+            UNIX_EPOCH.checked_sub(magnitude)
+        };
+        t.ok_or_else(|| Error::new(UT_ERR_OUT_OF_RANGE))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    fn from_monday_index(index: u32) -> Weekday {
+        match index {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+
+    /// Day number with Monday = 1 through Sunday = 7 (ISO 8601).
+    pub fn number_from_monday(&self) -> u32 {
+        *self as u32 + 1
+    }
+
+    /// Day number with Sunday = 1 through Saturday = 7.
+    pub fn number_from_sunday(&self) -> u32 {
+        (*self as u32 + 1) % 7 + 1
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum JapaneseEra {
+    Meiji,
+    Taisho,
+    Showa,
+    Heisei,
+    Reiwa,
+    /// An era code not known to this wrapper.
+    Unknown(i32),
+}
+
+impl JapaneseEra {
+    fn from_code(code: c_int) -> JapaneseEra {
+        match code {
+            0 => JapaneseEra::Reiwa,
+            1 => JapaneseEra::Heisei,
+            2 => JapaneseEra::Showa,
+            3 => JapaneseEra::Taisho,
+            4 => JapaneseEra::Meiji,
+            other => JapaneseEra::Unknown(other),
+        }
+    }
+
+    fn code(&self) -> c_int {
+        match *self {
+            JapaneseEra::Reiwa => 0,
+            JapaneseEra::Heisei => 1,
+            JapaneseEra::Showa => 2,
+            JapaneseEra::Taisho => 3,
+            JapaneseEra::Meiji => 4,
+            JapaneseEra::Unknown(code) => code,
+        }
+    }
+
+    /// Era name in romaji (e.g. "Reiwa"), or "Unknown".
+    pub fn name(&self) -> &'static str {
+        let name_ptr = unsafe { ut_japanese_era_name(self.code()) };
+        unsafe { CStr::from_ptr(name_ptr) }.to_str().unwrap_or("Unknown")
+    }
+}
+
+// --- Chrono Interop ---
+
+/// Preserves nanoseconds; instants beyond the `i64` nanosecond range saturate.
+///
+/// Leap seconds are ignored: chrono's leap-second representation is folded
+/// into the following second.
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
+    fn from(dt: chrono::DateTime<chrono::Utc>) -> Self {
+        let nanos = dt.timestamp() as i128 * NANOS_PER_SECOND as i128
+            + dt.timestamp_subsec_nanos() as i128;
+        Timestamp::from_nanos(saturate_nanos(nanos))
+    }
+}
+
+/// Fails if the instant falls outside chrono's representable span.
+#[cfg(feature = "chrono")]
+impl TryFrom<Timestamp> for chrono::DateTime<chrono::Utc> {
+    type Error = Error;
+
+    fn try_from(ts: Timestamp) -> Result<Self> {
+        let nanos = ts.as_nanos();
+        chrono::DateTime::from_timestamp(
+            nanos.div_euclid(NANOS_PER_SECOND),
+            nanos.rem_euclid(NANOS_PER_SECOND) as u32,
+        )
+        .ok_or_else(|| Error::new(UT_ERR_OUT_OF_RANGE))
+    }
+}
+
+// --- Time Interop ---
+
+/// Converts through UTC, preserving nanoseconds; instants beyond the `i64`
+/// nanosecond range saturate.
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Timestamp {
+    fn from(dt: time::OffsetDateTime) -> Self {
+        Timestamp::from_nanos(saturate_nanos(dt.unix_timestamp_nanos()))
+    }
+}
+
+/// Produces a UTC `OffsetDateTime`; fails outside the `time` crate's range.
+#[cfg(feature = "time")]
+impl TryFrom<Timestamp> for time::OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(ts: Timestamp) -> Result<Self> {
+        time::OffsetDateTime::from_unix_timestamp_nanos(ts.as_nanos() as i128)
+            .map_err(|_| Error::new(UT_ERR_OUT_OF_RANGE))
+    }
+}
+
+// --- Serde Support ---
+
+/// Serializes as the ISO-8601 string with nanoseconds; deserializes with the strict parser.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Timestamp {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Timestamp {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        struct IsoVisitor;
+
+        impl serde::de::Visitor<'_> for IsoVisitor {
+            type Value = Timestamp;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an ISO-8601 timestamp string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> core::result::Result<Timestamp, E> {
+                Timestamp::parse(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(IsoVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+const PRECISION_NAMES: [(&str, Precision); 4] = [
+    ("nanosecond", Precision::Nanosecond),
+    ("microsecond", Precision::Microsecond),
+    ("millisecond", Precision::Millisecond),
+    ("second", Precision::Second),
+];
+
+/// Serializes as a lowercase unit name such as `"millisecond"`.
+///
+/// `Precision::Error` is a detection result, not a setting, so serializing
+/// it fails and `"error"` is not accepted back.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Precision {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        match PRECISION_NAMES.iter().find(|(_, p)| p == self) {
+            Some((name, _)) => serializer.serialize_str(name),
+            None => Err(serde::ser::Error::custom("Precision::Error cannot be serialized")),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Precision {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        struct PrecisionVisitor;
+
+        impl serde::de::Visitor<'_> for PrecisionVisitor {
+            type Value = Precision;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a precision name such as \"millisecond\"")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> core::result::Result<Precision, E> {
+                match PRECISION_NAMES.iter().find(|(name, _)| *name == v) {
+                    Some((_, precision)) => Ok(*precision),
+                    None => Err(E::unknown_variant(v, &["nanosecond", "microsecond", "millisecond", "second"])),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(PrecisionVisitor)
+    }
+}
+
+/// Serialize as raw `i64` Unix nanoseconds, for use with `#[serde(with = "serde_nanos")]`.
+#[cfg(feature = "serde")]
+pub mod serde_nanos {
+    use super::Timestamp;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(ts.as_nanos())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        i64::deserialize(deserializer).map(Timestamp::from_nanos)
+    }
+}
+
+/// Integer Unix milliseconds, as used by JavaScript `Date`, for use with
+/// `#[serde(with = "serde_millis")]`.
+///
+/// Serializing drops sub-millisecond digits, rounding toward negative
+/// infinity like `Timestamp::as_millis`. Deserializing accepts only
+/// integers; floats such as `1.5` are rejected rather than rounded.
+#[cfg(feature = "serde")]
+pub mod serde_millis {
+    use super::Timestamp;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(ts.as_millis())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        let millis = i64::deserialize(deserializer)?;
+        Timestamp::from_unix_millis(millis).map_err(serde::de::Error::custom)
+    }
+}
+
+/// RFC 3339 strings with an explicit `+00:00` offset instead of `Z`, for use
+/// with `#[serde(with = "serde_rfc3339_offset")]`.
+///
+/// Deserializing accepts `Z` or any numeric offset (see `Timestamp::parse_rfc3339`).
+#[cfg(feature = "serde")]
+pub mod serde_rfc3339_offset {
+    use super::Timestamp;
+    use alloc::string::String;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&ts.format_rfc3339(false, true))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Timestamp::parse_rfc3339(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Compact 8-byte form for binary formats such as bincode, for use with
+/// `#[serde(with = "serde_compact")]`.
+///
+/// The encoding is the `serde_nanos` one: a bare `i64` of Unix nanoseconds.
+/// It is not self-describing and always means UTC.
+#[cfg(feature = "serde")]
+pub mod serde_compact {
+    pub use super::serde_nanos::{deserialize, serialize};
+}
+
+pub mod calendar {
+    use super::*;
+
+    pub fn gregorian_to_thai(year: i32) -> i32 {
+        unsafe { ut_gregorian_to_thai(year) }
+    }
+    pub fn thai_to_gregorian(year: i32) -> i32 {
+        unsafe { ut_thai_to_gregorian(year) }
+    }
+    pub fn gregorian_to_dangi(year: i32) -> i32 {
+        unsafe { ut_gregorian_to_dangi(year) }
+    }
+    pub fn dangi_to_gregorian(year: i32) -> i32 {
+        unsafe { ut_dangi_to_gregorian(year) }
+    }
+    pub fn gregorian_to_minguo(year: i32) -> i32 {
+        unsafe { ut_gregorian_to_minguo(year) }
+    }
+    pub fn minguo_to_gregorian(year: i32) -> i32 {
+        unsafe { ut_minguo_to_gregorian(year) }
+    }
+
+    /// Gregorian leap-year rule: divisible by 4, except centuries not divisible by 400.
+    pub fn is_gregorian_leap_year(year: i32) -> bool {
+        is_leap_year(year)
+    }
+
+    /// Number of days (28-31) in `month` of `year`. Fails for months outside 1-12.
+    pub fn days_in_month(year: i32, month: u32) -> Result<u32> {
+        match super::days_in_month(year, month) {
+            0 => Err(Error::new(UT_ERR_OUT_OF_RANGE)),
+            days => Ok(days),
+        }
+    }
+
+    /// Length of a month or weekday name.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub enum NameStyle {
+        /// `"September"`, `"Wednesday"`.
+        Full,
+        /// Three letters: `"Sep"`, `"Wed"`.
+        Abbreviated,
+    }
+
+    /// English name of `month` (1-12). Fails for months outside 1-12.
+    pub fn month_name(month: u32, style: NameStyle) -> Result<&'static str> {
+        let names = match style {
+            NameStyle::Full => &MONTH_NAMES,
+            NameStyle::Abbreviated => &MONTH_ABBR,
+        };
+        match month.checked_sub(1).and_then(|i| names.get(i as usize)) {
+            Some(name) => Ok(name),
+            None => Err(Error::new(UT_ERR_OUT_OF_RANGE)),
+        }
+    }
+
+    /// English name of `day`.
+    pub fn weekday_name(day: Weekday, style: NameStyle) -> &'static str {
+        let index = day.number_from_monday() as usize - 1;
+        match style {
+            NameStyle::Full => WEEKDAY_NAMES[index],
+            NameStyle::Abbreviated => WEEKDAY_ABBR[index],
+        }
+    }
+
+    /// Number of ISO 8601 weeks (52 or 53) in the ISO week-numbering `year`.
+    ///
+    /// A year has 53 weeks when January 1 is a Thursday, or a Wednesday in a leap year.
+    pub fn iso_weeks_in_year(year: i32) -> u32 {
+        super::iso_weeks_in_year(year)
+    }
+
+    const HEBREW_OFFSET: i32 = 3760;
+
+    /// Hebrew (Anno Mundi) year in progress on January 1 of the Gregorian year.
+    ///
+    /// The Hebrew year advances at Rosh Hashanah (September/October), so the
+    /// last months of a Gregorian year fall in the following Hebrew year.
+    pub fn gregorian_to_hebrew(year: i32) -> i32 {
+        year + HEBREW_OFFSET
+    }
+    /// Gregorian year containing the spring and summer of the Hebrew year (inverse of `gregorian_to_hebrew`).
+    pub fn hebrew_to_gregorian(year: i32) -> i32 {
+        year - HEBREW_OFFSET
+    }
+
+    const FRENCH_REPUBLICAN_OFFSET: i32 = 1792;
+
+    /// French Republican year in progress on January 1 of the Gregorian year.
+    ///
+    /// Year I began on 1 Vendémiaire (22 September 1792), so January 1, 1793
+    /// is in year 1 and the last months of a Gregorian year fall in the next
+    /// Republican year. The mapping is proleptic: Gregorian years before 1793
+    /// give zero or negative years rather than an error.
+    pub fn gregorian_to_french_republican(year: i32) -> i32 {
+        year - FRENCH_REPUBLICAN_OFFSET
+    }
+    /// Gregorian year containing the winter and summer of the Republican year (inverse of `gregorian_to_french_republican`).
+    pub fn french_republican_to_gregorian(year: i32) -> i32 {
+        year + FRENCH_REPUBLICAN_OFFSET
+    }
+
+    /// Day count of 1 Muharram 1 AH (622-07-19, proleptic Gregorian).
+    fn hijri_epoch() -> i64 {
+        days_from_civil(622, 7, 19)
+    }
+
+    /// Hijri year in progress on January 1 of the Gregorian year.
+    ///
+    /// Uses the tabular (arithmetic) Islamic calendar. Hijri years are about
+    /// 11 days shorter than Gregorian years, so this is approximate at year
+    /// granularity and observational calendars may differ near year boundaries.
+    pub fn gregorian_to_hijri(year: i32) -> i32 {
+        let d = days_from_civil(year, 1, 1) - hijri_epoch();
+        (30 * d + 10_646).div_euclid(10_631) as i32
+    }
+    /// Gregorian year in which the Hijri year begins (1 Muharram). Approximate, as above.
+    pub fn hijri_to_gregorian(year: i32) -> i32 {
+        let y = year as i64;
+        let start = hijri_epoch() + 354 * (y - 1) + (3 + 11 * y).div_euclid(30);
+        civil_from_days(start).0
+    }
+
+    /// Animal of the 12-year Chinese zodiac cycle.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub enum ZodiacAnimal {
+        Rat,
+        Ox,
+        Tiger,
+        Rabbit,
+        Dragon,
+        Snake,
+        Horse,
+        Goat,
+        Monkey,
+        Rooster,
+        Dog,
+        Pig,
+    }
+
+    const ZODIAC_CYCLE: [ZodiacAnimal; 12] = [
+        ZodiacAnimal::Rat,
+        ZodiacAnimal::Ox,
+        ZodiacAnimal::Tiger,
+        ZodiacAnimal::Rabbit,
+        ZodiacAnimal::Dragon,
+        ZodiacAnimal::Snake,
+        ZodiacAnimal::Horse,
+        ZodiacAnimal::Goat,
+        ZodiacAnimal::Monkey,
+        ZodiacAnimal::Rooster,
+        ZodiacAnimal::Dog,
+        ZodiacAnimal::Pig,
+    ];
+
+    impl ZodiacAnimal {
+        /// English name, e.g. "Rat".
+        pub fn name(&self) -> &'static str {
+            match self {
+                ZodiacAnimal::Rat => "Rat",
+                ZodiacAnimal::Ox => "Ox",
+                ZodiacAnimal::Tiger => "Tiger",
+                ZodiacAnimal::Rabbit => "Rabbit",
+                ZodiacAnimal::Dragon => "Dragon",
+                ZodiacAnimal::Snake => "Snake",
+                ZodiacAnimal::Horse => "Horse",
+                ZodiacAnimal::Goat => "Goat",
+                ZodiacAnimal::Monkey => "Monkey",
+                ZodiacAnimal::Rooster => "Rooster",
+                ZodiacAnimal::Dog => "Dog",
+                ZodiacAnimal::Pig => "Pig",
+            }
+        }
+    }
+
+    /// Zodiac animal of the lunar year that begins in `gregorian_year`.
+    ///
+    /// Anchored on 4 CE (a Rat year). January and early February belong to
+    /// the previous lunar year, which this year-level lookup does not model.
+    pub fn zodiac_animal(gregorian_year: i32) -> ZodiacAnimal {
+        ZODIAC_CYCLE[(gregorian_year as i64 - 4).rem_euclid(12) as usize]
+    }
+
+    /// Heavenly stem (天干) of the sexagenary cycle.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub enum Stem {
+        Jia,
+        Yi,
+        Bing,
+        Ding,
+        Wu,
+        Ji,
+        Geng,
+        Xin,
+        Ren,
+        Gui,
+    }
+
+    const STEMS: [(Stem, &str, char); 10] = [
+        (Stem::Jia, "Jia", '甲'),
+        (Stem::Yi, "Yi", '乙'),
+        (Stem::Bing, "Bing", '丙'),
+        (Stem::Ding, "Ding", '丁'),
+        (Stem::Wu, "Wu", '戊'),
+        (Stem::Ji, "Ji", '己'),
+        (Stem::Geng, "Geng", '庚'),
+        (Stem::Xin, "Xin", '辛'),
+        (Stem::Ren, "Ren", '壬'),
+        (Stem::Gui, "Gui", '癸'),
+    ];
+
+    impl Stem {
+        /// Pinyin name without tone marks, e.g. "Jia".
+        pub fn name(&self) -> &'static str {
+            STEMS[*self as usize].1
+        }
+
+        /// Chinese character, e.g. '甲'.
+        pub fn character(&self) -> char {
+            STEMS[*self as usize].2
+        }
+    }
+
+    /// Earthly branch (地支) of the sexagenary cycle.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub enum Branch {
+        Zi,
+        Chou,
+        Yin,
+        Mao,
+        Chen,
+        Si,
+        Wu,
+        Wei,
+        Shen,
+        You,
+        Xu,
+        Hai,
+    }
+
+    const BRANCHES: [(Branch, &str, char); 12] = [
+        (Branch::Zi, "Zi", '子'),
+        (Branch::Chou, "Chou", '丑'),
+        (Branch::Yin, "Yin", '寅'),
+        (Branch::Mao, "Mao", '卯'),
+        (Branch::Chen, "Chen", '辰'),
+        (Branch::Si, "Si", '巳'),
+        (Branch::Wu, "Wu", '午'),
+        (Branch::Wei, "Wei", '未'),
+        (Branch::Shen, "Shen", '申'),
+        (Branch::You, "You", '酉'),
+        (Branch::Xu, "Xu", '戌'),
+        (Branch::Hai, "Hai", '亥'),
+    ];
+
+    impl Branch {
+        /// Pinyin name without tone marks, e.g. "Zi".
+        pub fn name(&self) -> &'static str {
+            BRANCHES[*self as usize].1
+        }
+
+        /// Chinese character, e.g. '子'.
+        pub fn character(&self) -> char {
+            BRANCHES[*self as usize].2
+        }
+
+        /// Zodiac animal paired with this branch.
+        pub fn animal(&self) -> ZodiacAnimal {
+            ZODIAC_CYCLE[*self as usize]
+        }
+    }
+
+    /// Stem and branch naming the lunar year that begins in `gregorian_year`.
+    ///
+    /// Anchored on 4 CE (甲子); the cycle repeats every 60 years, so 1984 is
+    /// also 甲子. The lunar-new-year caveat of `zodiac_animal` applies.
+    pub fn sexagenary_year(gregorian_year: i32) -> (Stem, Branch) {
+        let offset = gregorian_year as i64 - 4;
+        (
+            STEMS[offset.rem_euclid(10) as usize].0,
+            BRANCHES[offset.rem_euclid(12) as usize].0,
+        )
+    }
+
+    /// Midnight UTC at the start of a Gregorian date.
+    const fn era_start(year: i32, month: u32, day: u32) -> Timestamp {
+        Timestamp::from_nanos_const(days_from_civil(year, month, day) * NANOS_PER_DAY)
+    }
+
+    /// First instant of each era known to `to_japanese_era`, oldest first.
+    const JAPANESE_ERA_TRANSITIONS: [(Timestamp, JapaneseEra); 5] = [
+        (era_start(1868, 1, 25), JapaneseEra::Meiji),
+        (era_start(1912, 7, 30), JapaneseEra::Taisho),
+        (era_start(1926, 12, 25), JapaneseEra::Showa),
+        (era_start(1989, 1, 8), JapaneseEra::Heisei),
+        (era_start(2019, 5, 1), JapaneseEra::Reiwa),
+    ];
+
+    /// Start instants of the supported Japanese eras in increasing order.
+    ///
+    /// Matches the boundaries used by `Timestamp::to_japanese_era` (midnight
+    /// UTC of the first day), so callers can binary-search locally instead of
+    /// calling into C per lookup.
+    pub fn japanese_era_transitions() -> &'static [(Timestamp, JapaneseEra)] {
+        &JAPANESE_ERA_TRANSITIONS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_now() {
+        let _ = Timestamp::now();
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_format_parse() {
+        let ts = Timestamp::now_monotonic();
+        let s = ts.format(true);
+        let parsed = Timestamp::parse(&s).unwrap();
+        assert_eq!(ts, parsed);
+    }
+    
+    #[test]
+    fn test_calendar() {
+        assert_eq!(calendar::gregorian_to_thai(2024), 2567);
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(-719_468), (0, 3, 1));
+    }
+
+    #[test]
+    fn test_civil_days_round_trip() {
+        for days in [-719_468, -1, 0, 1, 11_016, 19_782, 2_932_896] {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+    }
+}
+
+/// Exercises the `core`-only API so `cargo test --no-default-features`
+/// catches anything that accidentally starts requiring `alloc` or `std`.
+#[cfg(test)]
+mod no_std_api {
+    use super::*;
+    use core::time::Duration;
+
+    #[test]
+    fn test_core_api() {
+        let ts = Timestamp::from_nanos(1_734_177_600_000_000_000);
+        assert_eq!(ts.as_nanos(), 1_734_177_600_000_000_000);
+        assert_eq!((ts + Duration::from_secs(60)) - ts, Duration::from_secs(60));
+        assert_eq!(ts.checked_add_nanos(i64::MAX).unwrap_err().code(), UT_ERR_OVERFLOW);
+
+        let mut buf = [0u8; UT_MAX_STRING_LEN];
+        assert_eq!(ts.format_to_slice(&mut buf, false).unwrap(), "2024-12-14T12:00:00Z");
+        assert_eq!((ts.year(), ts.month(), ts.day()), (2024, 12, 14));
+        assert_eq!(Timestamp::parse_rfc2822("Sat, 14 Dec 2024 12:00:00 GMT").unwrap(), ts);
+        let c_str = CStr::from_bytes_with_nul(b"2024-12-14T12:00:00Z\0").unwrap();
+        assert_eq!(Timestamp::parse_cstr(c_str).unwrap(), ts);
+        assert_eq!(Timestamp::parse_date("2024-12-14").unwrap(), ts.start_of_day());
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc"))]
+    fn test_static_error() {
+        let err = Timestamp::parse_date("2024-12-1x").unwrap_err();
+        assert_eq!(err.code(), UT_ERR_INVALID_FORMAT);
+        assert_eq!(err.message(), "Invalid format");
+        let overflow = Timestamp::MAX.checked_add_nanos(1).unwrap_err();
+        assert_eq!(overflow.message(), "Arithmetic overflow");
+        assert!(core::mem::size_of::<Error>() <= 3 * core::mem::size_of::<usize>());
     }
 }
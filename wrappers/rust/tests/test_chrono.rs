@@ -0,0 +1,35 @@
+#![cfg(all(feature = "chrono", feature = "alloc"))]
+
+use chrono::{DateTime, TimeZone, Utc};
+use universal_timestamp::Timestamp;
+
+#[test]
+fn test_chrono_round_trip() {
+    let dt = Utc.with_ymd_and_hms(2024, 12, 14, 12, 0, 0).unwrap()
+        + chrono::Duration::nanoseconds(123_456_789);
+    let ts = Timestamp::from(dt);
+    assert_eq!(ts.format(true), "2024-12-14T12:00:00.123456789Z");
+    assert_eq!(DateTime::<Utc>::try_from(ts).unwrap(), dt);
+}
+
+#[test]
+fn test_chrono_pre_epoch() {
+    let ts = Timestamp::from_nanos(-1);
+    let dt = DateTime::<Utc>::try_from(ts).unwrap();
+    let expected = Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap()
+        + chrono::Duration::nanoseconds(999_999_999);
+    assert_eq!(dt, expected);
+    assert_eq!(Timestamp::from(dt), ts);
+}
+
+#[test]
+fn test_chrono_range() {
+    // Every Timestamp fits in chrono, but not the other way round
+    assert!(DateTime::<Utc>::try_from(Timestamp::MIN).is_ok());
+    assert!(DateTime::<Utc>::try_from(Timestamp::MAX).is_ok());
+
+    let far = Utc.with_ymd_and_hms(3000, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(Timestamp::from(far), Timestamp::MAX);
+    let early = Utc.with_ymd_and_hms(1000, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(Timestamp::from(early), Timestamp::MIN);
+}
@@ -1,4 +1,12 @@
-use universal_timestamp::Timestamp;
+#![cfg(feature = "std")]
+
+use std::ffi::{CStr, CString};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use universal_timestamp::calendar;
+use universal_timestamp::{
+    CalendarDelta, ErrorKind, JapaneseEra, Monotonic, Precision, Timestamp,
+    SnapMode, TimestampBuilder, Unit, Weekday,
+};
 
 #[test]
 fn test_integration_now() {
@@ -26,3 +34,1620 @@ fn test_calendar() {
     let thai_year = universal_timestamp::calendar::gregorian_to_thai(2024);
     assert_eq!(thai_year, 2567);
 }
+
+#[test]
+fn test_add_sub_duration() {
+    let ts = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    let later = ts + Duration::from_secs(90);
+    assert_eq!(later.format(false), "2024-12-14T12:01:30Z");
+    assert_eq!(later - Duration::from_secs(90), ts);
+
+    let mut t = ts;
+    t += Duration::from_nanos(5);
+    t -= Duration::from_nanos(2);
+    assert_eq!(t.as_nanos(), ts.as_nanos() + 3);
+}
+
+#[test]
+fn test_duration_saturation() {
+    let max = Timestamp::from_nanos(i64::MAX - 1);
+    assert_eq!((max + Duration::from_nanos(10)).as_nanos(), i64::MAX);
+
+    let min = Timestamp::from_nanos(i64::MIN + 1);
+    assert_eq!((min - Duration::from_nanos(10)).as_nanos(), i64::MIN);
+
+    // Larger than i64 nanoseconds can hold
+    let huge = Duration::from_secs(u64::MAX);
+    assert_eq!((Timestamp::from_nanos(0) + huge).as_nanos(), i64::MAX);
+    assert_eq!((Timestamp::from_nanos(0) - huge).as_nanos(), i64::MIN);
+}
+
+#[test]
+fn test_sub_timestamps() {
+    let a = Timestamp::from_nanos(1_000);
+    let b = Timestamp::from_nanos(4_500);
+    assert_eq!(b - a, Duration::from_nanos(3_500));
+    assert_eq!(a - b, Duration::from_nanos(3_500));
+
+    let gap = Timestamp::from_nanos(i64::MAX) - Timestamp::from_nanos(i64::MIN);
+    assert_eq!(gap, Duration::from_nanos(u64::MAX));
+}
+
+#[test]
+fn test_components() {
+    let ts = Timestamp::parse("2024-12-14T03:13:21.123456789Z").unwrap();
+    assert_eq!(ts.year(), 2024);
+    assert_eq!(ts.month(), 12);
+    assert_eq!(ts.day(), 14);
+    assert_eq!(ts.hour(), 3);
+    assert_eq!(ts.minute(), 13);
+    assert_eq!(ts.second(), 21);
+    assert_eq!(ts.nanosecond(), 123_456_789);
+
+    let leap = Timestamp::parse("2024-02-29T23:59:59Z").unwrap();
+    assert_eq!((leap.year(), leap.month(), leap.day()), (2024, 2, 29));
+}
+
+#[test]
+fn test_components_pre_epoch() {
+    let ts = Timestamp::from_nanos(-1);
+    assert_eq!((ts.year(), ts.month(), ts.day()), (1969, 12, 31));
+    assert_eq!((ts.hour(), ts.minute(), ts.second()), (23, 59, 59));
+    assert_eq!(ts.nanosecond(), 999_999_999);
+
+    let ts = Timestamp::parse("1900-03-01T06:30:00Z").unwrap();
+    assert_eq!((ts.year(), ts.month(), ts.day()), (1900, 3, 1));
+    assert_eq!((ts.hour(), ts.minute()), (6, 30));
+
+    let epoch = Timestamp::from_nanos(0);
+    assert_eq!((epoch.year(), epoch.month(), epoch.day(), epoch.hour()), (1970, 1, 1, 0));
+}
+
+#[test]
+fn test_from_ymd_hms() {
+    let ts = Timestamp::from_ymd_hms(2024, 2, 29, 18, 45, 7, 500).unwrap();
+    assert_eq!(ts.format(true), "2024-02-29T18:45:07.0000005Z");
+    assert_eq!((ts.year(), ts.month(), ts.day()), (2024, 2, 29));
+    assert_eq!((ts.hour(), ts.minute(), ts.second(), ts.nanosecond()), (18, 45, 7, 500));
+
+    assert_eq!(Timestamp::from_ymd_hms(1970, 1, 1, 0, 0, 0, 0).unwrap().as_nanos(), 0);
+    assert_eq!(
+        Timestamp::from_ymd_hms(1969, 12, 31, 23, 59, 59, 999_999_999).unwrap().as_nanos(),
+        -1
+    );
+}
+
+#[test]
+fn test_from_ymd_hms_invalid() {
+    assert!(Timestamp::from_ymd_hms(2024, 2, 30, 0, 0, 0, 0).is_err());
+    assert!(Timestamp::from_ymd_hms(2023, 2, 29, 0, 0, 0, 0).is_err());
+    assert!(Timestamp::from_ymd_hms(2024, 13, 1, 0, 0, 0, 0).is_err());
+    assert!(Timestamp::from_ymd_hms(2024, 1, 1, 24, 0, 0, 0).is_err());
+    assert!(Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0, 1_000_000_000).is_err());
+    assert!(Timestamp::from_ymd_hms(3000, 1, 1, 0, 0, 0, 0).is_err());
+}
+
+#[test]
+fn test_from_str() {
+    let ts: Timestamp = "2024-12-14T12:00:00Z".parse().unwrap();
+    assert_eq!(ts, Timestamp::parse("2024-12-14T12:00:00Z").unwrap());
+}
+
+#[test]
+fn test_from_str_invalid() {
+    let err = "2024-12-14 12:00:00".parse::<Timestamp>().unwrap_err();
+    assert_eq!(err.to_string(), "Invalid format");
+}
+
+#[test]
+fn test_system_time_round_trip() {
+    let st = UNIX_EPOCH + Duration::new(1_734_177_600, 123_456_789);
+    let ts = Timestamp::from(st);
+    assert_eq!(ts.as_nanos(), 1_734_177_600_123_456_789);
+    assert_eq!(SystemTime::try_from(ts).unwrap(), st);
+}
+
+#[test]
+fn test_system_time_pre_epoch() {
+    let st = UNIX_EPOCH - Duration::from_millis(1_500);
+    let ts = Timestamp::from(st);
+    assert_eq!(ts.as_nanos(), -1_500_000_000);
+    assert_eq!(SystemTime::try_from(ts).unwrap(), st);
+}
+
+#[test]
+fn test_unit_constructors() {
+    assert_eq!(Timestamp::from_unix_seconds(1_734_177_600).unwrap().format(false), "2024-12-14T12:00:00Z");
+    assert_eq!(Timestamp::from_unix_millis(1_500).unwrap().as_nanos(), 1_500_000_000);
+    assert_eq!(Timestamp::from_unix_micros(-7).unwrap().as_nanos(), -7_000);
+    assert_eq!(Timestamp::from_unix_seconds(-1).unwrap().format(false), "1969-12-31T23:59:59Z");
+
+    // i64::MAX / 1e6 is about 9.2e12 milliseconds
+    assert!(Timestamp::from_unix_millis(10_000_000_000_000).is_err());
+    assert!(Timestamp::from_unix_seconds(i64::MIN).is_err());
+}
+
+#[test]
+fn test_unit_accessors_floor() {
+    let ts = Timestamp::from_nanos(1_234_567_891);
+    assert_eq!((ts.as_unix_seconds(), ts.as_millis(), ts.as_micros()), (1, 1_234, 1_234_567));
+
+    let ts = Timestamp::from_nanos(-1);
+    assert_eq!((ts.as_unix_seconds(), ts.as_millis(), ts.as_micros()), (-1, -1, -1));
+
+    let ts = Timestamp::from_nanos(-1_500_000_000);
+    assert_eq!(ts.as_unix_seconds(), -2);
+}
+
+#[test]
+fn test_checked_add_nanos() {
+    let ts = Timestamp::from_nanos(i64::MAX - 10);
+    assert_eq!(ts.checked_add_nanos(10).unwrap().as_nanos(), i64::MAX);
+    let err = ts.checked_add_nanos(11).unwrap_err();
+    assert_eq!(err.to_string(), "Arithmetic overflow");
+
+    let ts = Timestamp::from_nanos(i64::MIN + 10);
+    assert_eq!(ts.checked_sub_nanos(10).unwrap().as_nanos(), i64::MIN);
+    assert!(ts.checked_sub_nanos(11).is_err());
+    assert!(ts.checked_add_nanos(-11).is_err());
+}
+
+#[test]
+fn test_saturating_add_nanos() {
+    let ts = Timestamp::from_nanos(i64::MAX - 10);
+    assert_eq!(ts.saturating_add_nanos(100).as_nanos(), i64::MAX);
+    assert_eq!(Timestamp::from_nanos(i64::MIN).saturating_add_nanos(-1).as_nanos(), i64::MIN);
+    assert_eq!(Timestamp::from_nanos(5).saturating_add_nanos(-7).as_nanos(), -2);
+}
+
+#[test]
+fn test_weekday() {
+    assert_eq!(Timestamp::from_nanos(0).weekday(), Weekday::Thursday);
+    assert_eq!(Timestamp::parse("2024-12-14T23:59:59Z").unwrap().weekday(), Weekday::Saturday);
+    assert_eq!(Timestamp::parse("2024-02-29T00:00:00Z").unwrap().weekday(), Weekday::Thursday);
+    assert_eq!(Timestamp::parse("2000-01-03T08:00:00Z").unwrap().weekday(), Weekday::Monday);
+}
+
+#[test]
+fn test_weekday_pre_epoch() {
+    // 1969-12-31T23:59:59 must still be Wednesday, not Thursday
+    assert_eq!(Timestamp::from_nanos(-1_000_000_000).weekday(), Weekday::Wednesday);
+    assert_eq!(Timestamp::parse("1969-07-20T20:17:00Z").unwrap().weekday(), Weekday::Sunday);
+}
+
+#[test]
+fn test_weekday_numbering() {
+    assert_eq!(Weekday::Monday.number_from_monday(), 1);
+    assert_eq!(Weekday::Sunday.number_from_monday(), 7);
+    assert_eq!(Weekday::Sunday.number_from_sunday(), 1);
+    assert_eq!(Weekday::Saturday.number_from_sunday(), 7);
+    assert_eq!(Weekday::Monday.number_from_sunday(), 2);
+}
+
+#[test]
+fn test_day_of_year() {
+    assert_eq!(Timestamp::parse("2023-01-01T00:00:00Z").unwrap().day_of_year(), 1);
+    assert_eq!(Timestamp::parse("2023-12-31T12:00:00Z").unwrap().day_of_year(), 365);
+    assert_eq!(Timestamp::parse("2024-12-31T23:59:59Z").unwrap().ordinal_date(), (2024, 366));
+    assert_eq!(Timestamp::parse("2024-03-01T00:00:00Z").unwrap().ordinal_date(), (2024, 61));
+}
+
+#[test]
+fn test_day_of_year_pre_epoch() {
+    assert_eq!(Timestamp::from_nanos(-1).ordinal_date(), (1969, 365));
+    assert_eq!(Timestamp::parse("1968-02-29T06:00:00Z").unwrap().ordinal_date(), (1968, 60));
+}
+
+#[test]
+fn test_calendar_hebrew() {
+    assert_eq!(universal_timestamp::calendar::gregorian_to_hebrew(2024), 5784);
+    assert_eq!(universal_timestamp::calendar::hebrew_to_gregorian(5784), 2024);
+}
+
+#[test]
+fn test_calendar_hijri() {
+    // 1 Muharram 1446 AH fell on 2024-07-07/08
+    assert_eq!(universal_timestamp::calendar::gregorian_to_hijri(2024), 1445);
+    assert_eq!(universal_timestamp::calendar::gregorian_to_hijri(2025), 1446);
+    assert_eq!(universal_timestamp::calendar::hijri_to_gregorian(1446), 2024);
+    assert_eq!(universal_timestamp::calendar::hijri_to_gregorian(1), 622);
+}
+
+#[test]
+fn test_japanese_era() {
+    let ts = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    let (era, year) = ts.to_japanese_era().unwrap();
+    assert_eq!((era, year), (JapaneseEra::Reiwa, 6));
+    assert_eq!(era.name(), "Reiwa");
+
+    let ts = Timestamp::parse("1989-01-07T12:00:00Z").unwrap();
+    assert_eq!(ts.to_japanese_era().unwrap(), (JapaneseEra::Showa, 64));
+    assert_eq!(JapaneseEra::Unknown(42).name(), "Unknown");
+}
+
+#[test]
+fn test_japanese_era_pre_meiji() {
+    let ts = Timestamp::parse("1860-01-01T00:00:00Z").unwrap();
+    assert!(ts.to_japanese_era().is_err());
+}
+
+#[test]
+fn test_truncate_to() {
+    let ts = Timestamp::parse("2024-12-14T12:00:05.987654321Z").unwrap();
+    assert_eq!(ts.truncate_to(Precision::Second).format(true), "2024-12-14T12:00:05Z");
+    assert_eq!(ts.truncate_to(Precision::Millisecond).format(true), "2024-12-14T12:00:05.987Z");
+    assert_eq!(ts.truncate_to(Precision::Nanosecond), ts);
+    assert_eq!(ts.truncate_to(Precision::Error), ts);
+
+    // Negative values floor away from the epoch
+    assert_eq!(Timestamp::from_nanos(-1).truncate_to(Precision::Second).as_nanos(), -1_000_000_000);
+}
+
+#[test]
+fn test_round_to() {
+    let ts = Timestamp::parse("2024-12-14T12:00:59.6Z").unwrap();
+    assert_eq!(ts.round_to(Precision::Second).format(true), "2024-12-14T12:01:00Z");
+
+    // Ties go to the even unit
+    assert_eq!(Timestamp::from_nanos(1_500).round_to(Precision::Microsecond).as_nanos(), 2_000);
+    assert_eq!(Timestamp::from_nanos(2_500).round_to(Precision::Microsecond).as_nanos(), 2_000);
+    assert_eq!(Timestamp::from_nanos(-1_500).round_to(Precision::Microsecond).as_nanos(), -2_000);
+    assert_eq!(Timestamp::from_nanos(-1_400).round_to(Precision::Microsecond).as_nanos(), -1_000);
+}
+
+#[test]
+fn test_default_is_epoch() {
+    assert_eq!(Timestamp::default().as_nanos(), 0);
+    assert_eq!(Timestamp::default().format(false), "1970-01-01T00:00:00Z");
+}
+
+#[test]
+fn test_constants() {
+    const START: Timestamp = Timestamp::EPOCH;
+    assert_eq!(START.as_nanos(), 0);
+    assert_eq!(Timestamp::MIN.as_nanos(), i64::MIN);
+    assert_eq!(Timestamp::MAX.as_nanos(), i64::MAX);
+    assert!(Timestamp::MIN < Timestamp::EPOCH && Timestamp::EPOCH < Timestamp::MAX);
+    assert_eq!(Timestamp::default(), Timestamp::EPOCH);
+}
+
+#[test]
+fn test_format_into_reuses_buffer() {
+    let mut buf = String::with_capacity(64);
+    let ptr = buf.as_ptr();
+
+    Timestamp::from_nanos(1_734_177_600_123_456_789).format_into(&mut buf, true);
+    assert_eq!(buf, "2024-12-14T12:00:00.123456789Z");
+
+    Timestamp::from_nanos(0).format_into(&mut buf, true);
+    assert_eq!(buf, "1970-01-01T00:00:00Z");
+    assert_eq!(buf.as_ptr(), ptr);
+    assert_eq!(buf.capacity(), 64);
+}
+
+#[test]
+fn test_format_to_slice() {
+    let ts = Timestamp::from_nanos(1_734_177_600_123_456_789);
+
+    let mut exact = [0u8; 30];
+    assert_eq!(ts.format_to_slice(&mut exact, true).unwrap(), "2024-12-14T12:00:00.123456789Z");
+
+    let mut big = [0u8; universal_timestamp::UT_MAX_STRING_LEN];
+    assert_eq!(ts.format_to_slice(&mut big, false).unwrap(), "2024-12-14T12:00:00Z");
+    assert_eq!(big[20], 0);
+
+    let mut small = [0u8; 29];
+    let err = ts.format_to_slice(&mut small, true).unwrap_err();
+    assert_eq!(err.to_string(), "Buffer too small");
+}
+
+#[test]
+fn test_error_kind() {
+    let err = Timestamp::parse("2024-02-30T00:00:00Z").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ParseFailure);
+    assert_eq!(err.code(), 2);
+    assert_eq!(err.to_string(), "Invalid date");
+
+    let err = Timestamp::parse("not a timestamp").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidFormat);
+    assert_eq!(err.code(), 1);
+
+    let err = Timestamp::parse("2024-12-14T25:00:00Z").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfRange);
+
+    let err = Timestamp::MAX.checked_add_nanos(1).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Overflow);
+}
+
+#[test]
+fn test_error_eq() {
+    let a = Timestamp::parse("2024-02-30T00:00:00Z").unwrap_err();
+    let b = Timestamp::parse("2023-02-29T00:00:00Z").unwrap_err();
+    assert_eq!(a, b);
+    assert_ne!(a, Timestamp::parse("garbage").unwrap_err());
+}
+
+#[test]
+fn test_range() {
+    let start = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    let end = Timestamp::parse("2024-12-14T12:01:00Z").unwrap();
+    let items: Vec<_> = Timestamp::range(start, end, Duration::from_secs(20)).collect();
+    assert_eq!(items, vec![start, start + Duration::from_secs(20), start + Duration::from_secs(40)]);
+}
+
+#[test]
+fn test_range_empty() {
+    let ts = Timestamp::from_nanos(1_000);
+    assert_eq!(Timestamp::range(ts, ts, Duration::from_nanos(1)).count(), 0);
+    assert_eq!(Timestamp::range(ts, Timestamp::EPOCH, Duration::from_nanos(1)).count(), 0);
+    assert_eq!(Timestamp::range(Timestamp::EPOCH, ts, Duration::ZERO).count(), 0);
+}
+
+#[test]
+fn test_range_overflow() {
+    let start = Timestamp::from_nanos(i64::MAX - 5);
+    let items: Vec<_> = Timestamp::range(start, Timestamp::MAX, Duration::from_nanos(2)).collect();
+    assert_eq!(items.len(), 3);
+
+    let huge = Duration::from_secs(u64::MAX);
+    assert_eq!(Timestamp::range(Timestamp::MIN, Timestamp::MAX, huge).count(), 1);
+}
+
+#[test]
+fn test_range_rev() {
+    let start = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    let end = Timestamp::parse("2024-12-14T12:01:00Z").unwrap();
+    let step = Duration::from_secs(20);
+    let items: Vec<_> = Timestamp::range_rev(start, end, step).collect();
+    assert_eq!(items, vec![start + step * 2, start + step, start]);
+
+    // Same items as range, descending, whether or not step divides the span
+    for (end, step) in [(end, step), (end, Duration::from_secs(7)), (start + step, step)] {
+        let mut forward: Vec<_> = Timestamp::range(start, end, step).collect();
+        forward.reverse();
+        assert_eq!(Timestamp::range_rev(start, end, step).collect::<Vec<_>>(), forward);
+    }
+    let items: Vec<_> = Timestamp::range_rev(Timestamp::from_nanos(0), Timestamp::from_nanos(10),
+                                             Duration::from_nanos(3)).collect();
+    assert_eq!(items.iter().map(|ts| ts.as_nanos()).collect::<Vec<_>>(), [9, 6, 3, 0]);
+
+    assert_eq!(Timestamp::range_rev(end, start, step).count(), 0);
+    assert_eq!(Timestamp::range_rev(start, end, Duration::ZERO).count(), 0);
+}
+
+#[test]
+fn test_range_rev_underflow() {
+    let end = Timestamp::from_nanos(i64::MIN + 5);
+    let items: Vec<_> = Timestamp::range_rev(Timestamp::MIN, end, Duration::from_nanos(2)).collect();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0].as_nanos(), i64::MIN + 4);
+    assert_eq!(items[2], Timestamp::MIN);
+
+    let huge = Duration::from_secs(u64::MAX);
+    assert_eq!(Timestamp::range_rev(Timestamp::MIN, Timestamp::MAX, huge).count(), 1);
+}
+
+#[test]
+fn test_calendar_leap_year() {
+    use universal_timestamp::calendar::is_gregorian_leap_year;
+    assert!(is_gregorian_leap_year(2000));
+    assert!(!is_gregorian_leap_year(1900));
+    assert!(is_gregorian_leap_year(2024));
+    assert!(!is_gregorian_leap_year(2023));
+
+    assert!(Timestamp::parse("2024-07-01T00:00:00Z").unwrap().is_in_leap_year());
+    assert!(!Timestamp::parse("2023-07-01T00:00:00Z").unwrap().is_in_leap_year());
+}
+
+#[test]
+fn test_calendar_days_in_month() {
+    assert_eq!(calendar::days_in_month(2024, 2).unwrap(), 29);
+    assert_eq!(calendar::days_in_month(2023, 2).unwrap(), 28);
+    assert_eq!(calendar::days_in_month(2024, 4).unwrap(), 30);
+    assert_eq!(calendar::days_in_month(2024, 1).unwrap(), 31);
+    assert_eq!(calendar::days_in_month(2024, 13).unwrap_err().kind(), ErrorKind::OutOfRange);
+    assert!(calendar::days_in_month(2024, 0).is_err());
+}
+
+#[test]
+fn test_format_iso_week() {
+    assert_eq!(Timestamp::parse("2024-12-14T12:00:00Z").unwrap().format_iso_week(), "2024-W50-6");
+    // Late December belonging to week 1 of the next ISO year
+    assert_eq!(Timestamp::parse("2024-12-30T00:00:00Z").unwrap().format_iso_week(), "2025-W01-1");
+    // Early January belonging to the last week of the previous ISO year
+    assert_eq!(Timestamp::parse("2021-01-03T23:00:00Z").unwrap().format_iso_week(), "2020-W53-7");
+    // Pre-epoch and past midnight: 1969-12-31 was the Wednesday of 1970-W01
+    let ts = Timestamp::parse("1969-12-31T12:00:00Z").unwrap();
+    assert_eq!(ts.format_iso_week(), "1970-W01-3");
+    assert_eq!(Timestamp::parse_iso_week("1970-W01-3T12:00:00Z").unwrap(), ts);
+    assert_eq!(Timestamp::parse("1969-12-28T23:59:59Z").unwrap().format_iso_week(), "1969-W52-7");
+}
+
+#[test]
+fn test_parse_iso_week() {
+    let ts = Timestamp::parse_iso_week("2024-W50-6").unwrap();
+    assert_eq!(ts.format(false), "2024-12-14T00:00:00Z");
+    assert_eq!(ts.format_iso_week(), "2024-W50-6");
+
+    let ts = Timestamp::parse_iso_week("2025-W01-1T08:30:00Z").unwrap();
+    assert_eq!(ts.format(false), "2024-12-30T08:30:00Z");
+}
+
+#[test]
+fn test_parse_iso_week_53() {
+    // 2020 has 53 ISO weeks, 2021 does not
+    let ts = Timestamp::parse_iso_week("2020-W53-5").unwrap();
+    assert_eq!(ts.format(false), "2021-01-01T00:00:00Z");
+    assert_eq!(ts.format_iso_week(), "2020-W53-5");
+    assert_eq!(Timestamp::parse_iso_week("2021-W53-1").unwrap_err().kind(), ErrorKind::ParseFailure);
+    assert!(Timestamp::parse_iso_week("2024-W54-1").is_err());
+    assert!(Timestamp::parse_iso_week("2024-W10-8").is_err());
+}
+
+#[test]
+fn test_parse_iso_week_malformed() {
+    for s in ["2024-50-6", "2024-W5-6", "2024-W5é6", "2024-w50-6", "2024-W50-6 ", "2024-W50-6T25:00:00Z"] {
+        assert!(Timestamp::parse_iso_week(s).is_err(), "{}", s);
+    }
+    assert_eq!(Timestamp::parse_iso_week("2024-Wxx-1").unwrap_err().kind(), ErrorKind::InvalidFormat);
+}
+
+#[test]
+fn test_rfc2822_round_trip() {
+    let ts = Timestamp::parse("2024-12-14T12:00:00.75Z").unwrap();
+    let s = ts.format_rfc2822();
+    assert_eq!(s, "Sat, 14 Dec 2024 12:00:00 +0000");
+    assert_eq!(Timestamp::parse_rfc2822(&s).unwrap(), ts.truncate_to(Precision::Second));
+    assert_eq!(Timestamp::from_nanos(0).format_rfc2822(), "Thu, 01 Jan 1970 00:00:00 +0000");
+}
+
+#[test]
+fn test_rfc2822_offset() {
+    let ts = Timestamp::parse_rfc2822("Sat, 14 Dec 2024 14:00:00 +0200").unwrap();
+    assert_eq!(ts.format(false), "2024-12-14T12:00:00Z");
+    let ts = Timestamp::parse_rfc2822("Sat, 14 Dec 2024 04:30:00 -0730").unwrap();
+    assert_eq!(ts.format(false), "2024-12-14T12:00:00Z");
+    // Hours beyond 23 are not a valid zone offset
+    assert!(Timestamp::parse_rfc2822("Sat, 14 Dec 2024 14:00:00 +2500").is_err());
+    assert!(Timestamp::parse_rfc2822("Sat, 14 Dec 2024 14:00:00 -2400").is_err());
+}
+
+#[test]
+fn test_rfc2822_obsolete_forms() {
+    let expected = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    assert_eq!(Timestamp::parse_rfc2822("14 Dec 24 12:00 GMT").unwrap(), expected);
+    assert_eq!(Timestamp::parse_rfc2822("sat, 14 dec 2024 07:00:00 EST").unwrap(), expected);
+    assert_eq!(
+        Timestamp::parse_rfc2822("Fri, 31 Dec 99 23:59:59 UT").unwrap().format(false),
+        "1999-12-31T23:59:59Z"
+    );
+
+    for s in ["14 Dec 2024", "Xyz, 14 Dec 2024 12:00:00 +0000", "14 Foo 2024 12:00:00 +0000",
+              "14 Dec 2024 12:00:00 +02", "31 Feb 2024 12:00:00 +0000", "14 Dec 2024 12:00:00 +0000 x"] {
+        assert!(Timestamp::parse_rfc2822(s).is_err(), "{}", s);
+    }
+}
+
+#[test]
+fn test_parse_rfc3339_offsets() {
+    let utc = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    assert_eq!(Timestamp::parse_rfc3339("2024-12-14T14:00:00+02:00").unwrap(), utc);
+    assert_eq!(Timestamp::parse_rfc3339("2024-12-14T04:00:00-08:00").unwrap(), utc);
+    assert_eq!(Timestamp::parse_rfc3339("2024-12-14T17:30:00+05:30").unwrap(), utc);
+    assert_eq!(Timestamp::parse_rfc3339("2024-12-14T12:00:00Z").unwrap(), utc);
+    assert_eq!(Timestamp::parse_rfc3339("2024-12-14T12:00:00-00:00").unwrap(), utc);
+
+    // Offsets can move the instant across a date boundary
+    let ts = Timestamp::parse_rfc3339("2024-01-01T01:00:00.5+03:00").unwrap();
+    assert_eq!(ts.format(true), "2023-12-31T22:00:00.5Z");
+}
+
+#[test]
+fn test_parse_rfc3339_invalid() {
+    for s in ["2024-12-14T12:00:00", "2024-12-14T12:00:00+0200", "2024-12-14T12:00:00+2:00",
+              "2024-12-14T12:00:00+24:00", "2024-12-14T12:00:00+02:60", "2024-12-14 12:00:00+02:00"] {
+        assert!(Timestamp::parse_rfc3339(s).is_err(), "{}", s);
+    }
+}
+
+#[test]
+fn test_with_fields() {
+    let ts = Timestamp::parse("2024-12-14T12:34:56.789Z").unwrap();
+    let top = ts.with_second(0).unwrap().with_nanosecond(0).unwrap();
+    assert_eq!(top.format(true), "2024-12-14T12:34:00Z");
+    assert_eq!((top.year(), top.month(), top.day(), top.hour(), top.minute()), (2024, 12, 14, 12, 34));
+
+    assert_eq!(ts.with_minute(0).unwrap().format(true), "2024-12-14T12:00:56.789Z");
+    assert_eq!(ts.with_hour(23).unwrap().format(true), "2024-12-14T23:34:56.789Z");
+
+    let pre = Timestamp::from_nanos(-1).with_hour(0).unwrap();
+    assert_eq!(pre.format(true), "1969-12-31T00:59:59.999999999Z");
+}
+
+#[test]
+fn test_with_fields_out_of_range() {
+    let ts = Timestamp::parse("2024-12-14T12:34:56Z").unwrap();
+    assert!(ts.with_second(60).is_err());
+    assert!(ts.with_minute(60).is_err());
+    assert!(ts.with_hour(24).is_err());
+    assert!(ts.with_nanosecond(1_000_000_000).is_err());
+}
+
+#[test]
+fn test_signed_duration_nanos_since() {
+    let a = Timestamp::from_nanos(1_000);
+    let b = Timestamp::from_nanos(4_000);
+    assert_eq!(b.signed_duration_nanos_since(a), 3_000);
+    assert_eq!(a.signed_duration_nanos_since(b), -3_000);
+    assert_eq!(a.diff(b), Duration::from_nanos(3_000));
+    assert_eq!(b.diff(a), Duration::from_nanos(3_000));
+}
+
+#[test]
+fn test_signed_duration_saturates() {
+    assert_eq!(Timestamp::MAX.signed_duration_nanos_since(Timestamp::MIN), i64::MAX);
+    assert_eq!(Timestamp::MIN.signed_duration_nanos_since(Timestamp::MAX), i64::MIN);
+    assert_eq!(Timestamp::MAX.diff(Timestamp::MIN), Duration::from_nanos(u64::MAX));
+}
+
+#[test]
+fn test_from_nanos_const() {
+    const RELEASE: Timestamp = Timestamp::from_nanos_const(1_734_177_600_000_000_000);
+    static TABLE: [Timestamp; 2] = [Timestamp::from_nanos_const(-1), Timestamp::from_nanos_const(i64::MAX)];
+    assert_eq!(RELEASE, Timestamp::from_nanos(1_734_177_600_000_000_000));
+    assert_eq!(RELEASE.format(false), "2024-12-14T12:00:00Z");
+    assert_eq!(TABLE[0], Timestamp::from_nanos(-1));
+    assert_eq!(TABLE[1], Timestamp::MAX);
+}
+
+#[test]
+fn test_parse_many() {
+    let inputs = [
+        "2024-12-14T12:00:00Z",
+        "not a timestamp",
+        "2024-02-30T00:00:00Z",
+        "1969-12-31T23:59:59.999999999Z",
+        "2024-12-14T12:00:00Z\0",
+        "",
+    ];
+    let results = Timestamp::parse_many(&inputs);
+    assert_eq!(results.len(), inputs.len());
+    for (input, result) in inputs.iter().zip(&results) {
+        assert_eq!(*result, Timestamp::parse(input));
+    }
+    assert!(results[0].is_ok() && results[3].is_ok());
+    assert_eq!(results[4].as_ref().unwrap_err().kind(), ErrorKind::InvalidFormat);
+    // An interior NUL is reported at its byte offset, like parse does
+    assert_eq!(results[4].as_ref().unwrap_err().position(), Some(20));
+    let results = Timestamp::parse_many(&["2024-12\0-14T12:00:00Z"]);
+    assert_eq!(results[0].as_ref().unwrap_err().position(), Some(7));
+    assert!(Timestamp::parse_many(&[]).is_empty());
+}
+
+#[test]
+fn test_parse_cstr() {
+    for input in ["2024-12-14T12:00:00.123456789Z", "2024-12-14T12:00:00", "2024-13-01T00:00:00Z"] {
+        let c_string = CString::new(input).unwrap();
+        assert_eq!(Timestamp::parse_cstr(&c_string), Timestamp::parse(input));
+    }
+    let c_str = CStr::from_bytes_with_nul(b"1970-01-01T00:00:00Z\0").unwrap();
+    assert_eq!(Timestamp::parse_cstr(c_str).unwrap(), Timestamp::EPOCH);
+}
+
+#[test]
+fn test_parse_rejects_interior_nul() {
+    let err = Timestamp::parse("2024-12-14T12:00:00Z\0garbage").unwrap_err();
+    assert_eq!(err.code(), -1);
+    assert_eq!(err.kind(), ErrorKind::InvalidFormat);
+}
+
+#[test]
+fn test_monotonic() {
+    let t1 = Monotonic::now();
+    std::thread::sleep(Duration::from_millis(1));
+    let t2 = Monotonic::now();
+    assert!(t2 > t1);
+    assert!(t2.duration_since(t1) >= Duration::from_millis(1));
+    assert!(t1.elapsed() >= t2.duration_since(t1));
+    assert_eq!(t1.duration_since(t2), Duration::ZERO);
+}
+
+#[test]
+fn test_clamp_min_max() {
+    let lo = Timestamp::from_nanos(1_000);
+    let hi = Timestamp::from_nanos(2_000);
+    assert_eq!(Timestamp::from_nanos(500).clamp(lo, hi), lo);
+    assert_eq!(Timestamp::from_nanos(1_500).clamp(lo, hi), Timestamp::from_nanos(1_500));
+    assert_eq!(Timestamp::from_nanos(2_500).clamp(lo, hi), hi);
+    assert_eq!(lo.clamp(lo, lo), lo);
+    assert_eq!(lo.min(hi), lo);
+    assert_eq!(hi.min(lo), lo);
+    assert_eq!(lo.max(hi), hi);
+}
+
+#[test]
+#[should_panic]
+fn test_clamp_panics_on_inverted_range() {
+    let _ = Timestamp::EPOCH.clamp(Timestamp::MAX, Timestamp::MIN);
+}
+
+#[test]
+fn test_try_from_i64() {
+    let ts = Timestamp::try_from(1_734_177_600_000_000_000).unwrap();
+    assert_eq!(ts, Timestamp::from_nanos(1_734_177_600_000_000_000));
+    assert_eq!(Timestamp::try_from(i64::MAX).unwrap(), Timestamp::MAX);
+
+    let min_safe = -9_223_372_036_000_000_000;
+    let earliest = Timestamp::try_from(min_safe).unwrap();
+    assert_eq!(earliest.format(false), "1677-09-21T00:12:44Z");
+    assert_eq!(Timestamp::parse(&earliest.format(true)).unwrap(), earliest);
+    assert_eq!(Timestamp::parse(&Timestamp::MAX.format(true)).unwrap(), Timestamp::MAX);
+
+    for nanos in [i64::MIN, min_safe - 1] {
+        let err = Timestamp::try_from(nanos).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OutOfRange);
+    }
+}
+
+#[test]
+fn test_quarter_and_half() {
+    let at = |month| Timestamp::from_ymd_hms(2024, month, 1, 0, 0, 0, 0).unwrap();
+    assert_eq!((at(1).quarter(), at(1).half()), (1, 1));
+    assert_eq!((at(3).quarter(), at(3).half()), (1, 1));
+    assert_eq!((at(4).quarter(), at(4).half()), (2, 1));
+    assert_eq!((at(7).quarter(), at(7).half()), (3, 2));
+    assert_eq!((at(12).quarter(), at(12).half()), (4, 2));
+    // 1969-12-31T23:59:59.999999999Z is still in Q4.
+    assert_eq!(Timestamp::from_nanos(-1).quarter(), 4);
+}
+
+#[test]
+fn test_elapsed_and_until() {
+    let past = Timestamp::now() - Duration::from_secs(3600);
+    assert!(past.elapsed() >= Duration::from_secs(3600));
+    assert_eq!(past.until(), None);
+
+    let future = Timestamp::now() + Duration::from_secs(3600);
+    assert_eq!(future.elapsed(), Duration::ZERO);
+    let remaining = future.until().unwrap();
+    assert!(remaining > Duration::ZERO && remaining <= Duration::from_secs(3600));
+}
+
+#[test]
+fn test_format_with_precision() {
+    let ts = Timestamp::from_nanos(1_734_177_600_123_456_789);
+    assert_eq!(ts.format_with_precision(Precision::Second), "2024-12-14T12:00:00Z");
+    assert_eq!(ts.format_with_precision(Precision::Millisecond), "2024-12-14T12:00:00.123Z");
+    assert_eq!(ts.format_with_precision(Precision::Microsecond), "2024-12-14T12:00:00.123456Z");
+    assert_eq!(ts.format_with_precision(Precision::Nanosecond), "2024-12-14T12:00:00.123456789Z");
+}
+
+#[test]
+fn test_format_with_precision_pads() {
+    let ts = Timestamp::from_nanos(1_734_177_600_001_000_000);
+    assert_eq!(ts.format_with_precision(Precision::Millisecond), "2024-12-14T12:00:00.001Z");
+    assert_eq!(ts.format_with_precision(Precision::Nanosecond), "2024-12-14T12:00:00.001000000Z");
+    let whole = Timestamp::from_nanos(1_734_177_600_000_000_000);
+    assert_eq!(whole.format_with_precision(Precision::Microsecond), "2024-12-14T12:00:00.000000Z");
+    let pre_epoch = Timestamp::from_nanos(-1);
+    assert_eq!(pre_epoch.format_with_precision(Precision::Millisecond), "1969-12-31T23:59:59.999Z");
+}
+
+#[test]
+fn test_zodiac_animal() {
+    use universal_timestamp::calendar::{zodiac_animal, ZodiacAnimal};
+    assert_eq!(zodiac_animal(2020), ZodiacAnimal::Rat);
+    assert_eq!(zodiac_animal(2024), ZodiacAnimal::Dragon);
+    assert_eq!(zodiac_animal(2025).name(), "Snake");
+    assert_eq!(zodiac_animal(1984), ZodiacAnimal::Rat);
+    assert_eq!(zodiac_animal(4), ZodiacAnimal::Rat);
+    assert_eq!(zodiac_animal(3), ZodiacAnimal::Pig);
+    assert_eq!(zodiac_animal(-8), ZodiacAnimal::Rat);
+    assert_eq!(zodiac_animal(i32::MIN), zodiac_animal(i32::MIN + 12));
+}
+
+#[test]
+fn test_sexagenary_year() {
+    use universal_timestamp::calendar::{sexagenary_year, zodiac_animal, Branch, Stem};
+    assert_eq!(sexagenary_year(1984), (Stem::Jia, Branch::Zi));
+    assert_eq!(sexagenary_year(1985), (Stem::Yi, Branch::Chou));
+    assert_eq!(sexagenary_year(1983), (Stem::Gui, Branch::Hai));
+    assert_eq!(sexagenary_year(2024), (Stem::Jia, Branch::Chen));
+    assert_eq!(sexagenary_year(1984 - 60), sexagenary_year(1984));
+    assert_eq!(sexagenary_year(-56), (Stem::Jia, Branch::Zi));
+
+    let (stem, branch) = sexagenary_year(1984);
+    assert_eq!((stem.character(), branch.character()), ('甲', '子'));
+    assert_eq!((stem.name(), branch.name()), ("Jia", "Zi"));
+    for year in 2000..2012 {
+        assert_eq!(sexagenary_year(year).1.animal(), zodiac_animal(year));
+    }
+}
+
+#[test]
+fn test_approx_eq() {
+    let us = Duration::from_micros(1);
+    let ts = Timestamp::from_nanos(1_734_177_600_000_000_000);
+    assert!(ts.approx_eq(ts, Duration::ZERO));
+    assert!(ts.approx_eq(ts.saturating_add_nanos(999), us));
+    assert!(ts.approx_eq(ts.saturating_add_nanos(-1_000), us));
+    assert!(!ts.approx_eq(ts.saturating_add_nanos(1_001), us));
+    assert!(!ts.saturating_add_nanos(-1_001).approx_eq(ts, us));
+
+    assert!(!Timestamp::MIN.approx_eq(Timestamp::MAX, us));
+    assert!(Timestamp::MAX.approx_eq(Timestamp::MIN, Duration::from_nanos(u64::MAX)));
+    assert!(Timestamp::MIN.approx_eq(Timestamp::MAX, Duration::MAX));
+}
+
+#[test]
+fn test_start_and_end_of_day() {
+    let morning = Timestamp::from_ymd_hms(2024, 12, 14, 8, 30, 0, 0).unwrap();
+    let afternoon = Timestamp::from_ymd_hms(2024, 12, 14, 15, 45, 12, 345).unwrap();
+    let midnight = morning.start_of_day();
+    assert_eq!(midnight, afternoon.start_of_day());
+    assert_eq!((midnight.hour(), midnight.minute(), midnight.second(), midnight.nanosecond()), (0, 0, 0, 0));
+    assert_eq!(midnight.day(), 14);
+    assert_eq!(midnight.start_of_day(), midnight);
+
+    let end = afternoon.end_of_day();
+    assert_eq!(end.format(true), "2024-12-14T23:59:59.999999999Z");
+    assert_eq!(end.start_of_day(), midnight);
+    assert_eq!(end.saturating_add_nanos(1), midnight + Duration::from_secs(86_400));
+}
+
+#[test]
+fn test_start_and_end_of_day_pre_epoch() {
+    let ts = Timestamp::from_nanos(-1);
+    assert_eq!(ts.start_of_day().format(false), "1969-12-31T00:00:00Z");
+    assert_eq!(ts.end_of_day(), ts);
+    assert_eq!(Timestamp::from_nanos(-86_400_000_000_000).start_of_day().format(false), "1969-12-31T00:00:00Z");
+
+    assert_eq!(Timestamp::MIN.start_of_day(), Timestamp::MIN);
+    assert_eq!(Timestamp::MAX.end_of_day(), Timestamp::MAX);
+}
+
+#[test]
+fn test_start_of_month_and_year() {
+    let ts = Timestamp::from_ymd_hms(2024, 2, 29, 13, 14, 15, 16).unwrap();
+    assert_eq!(ts.start_of_month().format(true), "2024-02-01T00:00:00Z");
+    assert_eq!(ts.start_of_year().format(true), "2024-01-01T00:00:00Z");
+
+    let dec = Timestamp::from_ymd_hms(2024, 12, 31, 23, 59, 59, 999_999_999).unwrap();
+    assert_eq!(dec.start_of_month().format(false), "2024-12-01T00:00:00Z");
+    assert_eq!(dec.start_of_year().format(false), "2024-01-01T00:00:00Z");
+    assert_eq!(dec.saturating_add_nanos(1).start_of_year().format(false), "2025-01-01T00:00:00Z");
+
+    let pre_epoch = Timestamp::from_nanos(-1);
+    assert_eq!(pre_epoch.start_of_month().format(false), "1969-12-01T00:00:00Z");
+    assert_eq!(pre_epoch.start_of_year().format(false), "1969-01-01T00:00:00Z");
+    assert_eq!(Timestamp::MIN.start_of_year(), Timestamp::MIN);
+    assert_eq!(Timestamp::MAX.start_of_month().format(false), "2262-04-01T00:00:00Z");
+}
+
+#[test]
+fn test_add_months() {
+    let jan31 = Timestamp::from_ymd_hms(2024, 1, 31, 10, 20, 30, 40).unwrap();
+    assert_eq!(jan31.add_months(1).unwrap().format(true), "2024-02-29T10:20:30.00000004Z");
+    assert_eq!(jan31.add_months(13).unwrap().format(false), "2025-02-28T10:20:30Z");
+    assert_eq!(jan31.add_months(0).unwrap(), jan31);
+
+    let mar15 = Timestamp::from_ymd_hms(2024, 3, 15, 0, 0, 0, 0).unwrap();
+    assert_eq!(mar15.add_months(-3).unwrap().format(false), "2023-12-15T00:00:00Z");
+    assert_eq!(mar15.add_months(-15).unwrap().format(false), "2022-12-15T00:00:00Z");
+    assert_eq!(Timestamp::from_nanos(-1).add_months(1).unwrap().format(false), "1970-01-31T23:59:59Z");
+}
+
+#[test]
+fn test_add_years() {
+    let leap_day = Timestamp::from_ymd_hms(2024, 2, 29, 12, 0, 0, 0).unwrap();
+    assert_eq!(leap_day.add_years(1).unwrap().format(false), "2025-02-28T12:00:00Z");
+    assert_eq!(leap_day.add_years(4).unwrap().format(false), "2028-02-29T12:00:00Z");
+    assert_eq!(leap_day.add_years(-100).unwrap().format(false), "1924-02-29T12:00:00Z");
+
+    assert_eq!(leap_day.add_years(300).unwrap_err().kind(), ErrorKind::Overflow);
+    assert_eq!(leap_day.add_years(i32::MAX).unwrap_err().kind(), ErrorKind::Overflow);
+    assert_eq!(leap_day.add_months(i32::MIN).unwrap_err().kind(), ErrorKind::Overflow);
+}
+
+#[test]
+fn test_unix_seconds_f64() {
+    let ts = Timestamp::from_nanos(1_734_177_600_123_456_789);
+    let secs = ts.as_unix_seconds_f64();
+    assert!((secs - 1_734_177_600.123_456_8).abs() < 1e-6);
+    let back = Timestamp::from_unix_seconds_f64(secs).unwrap();
+    assert!(back.approx_eq(ts, Duration::from_micros(1)));
+
+    assert_eq!(Timestamp::from_unix_seconds_f64(1.5).unwrap().as_nanos(), 1_500_000_000);
+    assert_eq!(Timestamp::from_unix_seconds_f64(-1.5).unwrap().as_nanos(), -1_500_000_000);
+    assert_eq!(Timestamp::from_unix_seconds_f64(0.3).unwrap().as_nanos(), 300_000_000);
+    assert_eq!(Timestamp::from_nanos(-1_500_000_000).as_unix_seconds_f64(), -1.5);
+}
+
+#[test]
+fn test_unix_seconds_f64_rejects_non_finite() {
+    for secs in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e10, -1e10] {
+        let err = Timestamp::from_unix_seconds_f64(secs).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OutOfRange);
+    }
+}
+
+#[test]
+fn test_clock_resolution() {
+    let before = universal_timestamp::get_clock_precision();
+    let resolution = universal_timestamp::get_clock_resolution();
+    let after = universal_timestamp::get_clock_precision();
+    assert!(resolution > Duration::ZERO && resolution <= Duration::from_secs(1));
+    if before == after {
+        let expected = match before {
+            Precision::Nanosecond => Duration::from_nanos(1),
+            Precision::Microsecond => Duration::from_micros(1),
+            Precision::Millisecond => Duration::from_millis(1),
+            Precision::Second | Precision::Error => Duration::from_secs(1),
+        };
+        assert_eq!(resolution, expected);
+    }
+}
+
+#[test]
+fn test_utc_offset_in() {
+    let ts = Timestamp::from_ymd_hms(2024, 7, 1, 12, 0, 0, 0).unwrap();
+    for tz in ["UTC", "GMT", "Z", "Etc/UTC", "+00:00", "UTC-00:00"] {
+        assert_eq!(ts.utc_offset_in(tz).unwrap(), 0, "{}", tz);
+    }
+    assert_eq!(ts.utc_offset_in("+05:30").unwrap(), 330);
+    assert_eq!(ts.utc_offset_in("UTC-08:00").unwrap(), -480);
+    assert_eq!(ts.utc_offset_in("GMT+02:00").unwrap(), 120);
+}
+
+#[test]
+fn test_utc_offset_in_rejects_unknown_zones() {
+    let ts = Timestamp::EPOCH;
+    for tz in ["Europe/Berlin", "utc", "", "PST"] {
+        assert_eq!(ts.utc_offset_in(tz).unwrap_err().kind(), ErrorKind::UnknownTimeZone, "{}", tz);
+    }
+    assert_eq!(ts.utc_offset_in("+2").unwrap_err().kind(), ErrorKind::InvalidFormat);
+    assert_eq!(ts.utc_offset_in("+24:00").unwrap_err().kind(), ErrorKind::OutOfRange);
+}
+
+#[test]
+fn test_months_between() {
+    let start = Timestamp::from_ymd_hms(2024, 11, 15, 12, 0, 0, 0).unwrap();
+    let end = Timestamp::from_ymd_hms(2025, 2, 1, 0, 0, 0, 1).unwrap();
+    let months: Vec<String> = Timestamp::months_between(start, end).map(|m| m.format(false)).collect();
+    assert_eq!(
+        months,
+        ["2024-11-01T00:00:00Z", "2024-12-01T00:00:00Z", "2025-01-01T00:00:00Z", "2025-02-01T00:00:00Z"]
+    );
+    // An exclusive end on the 1st does not include that month.
+    let feb1 = Timestamp::from_ymd_hms(2025, 2, 1, 0, 0, 0, 0).unwrap();
+    assert_eq!(Timestamp::months_between(start, feb1).count(), 3);
+}
+
+#[test]
+fn test_months_between_single_and_empty() {
+    let start = Timestamp::from_ymd_hms(2024, 2, 10, 0, 0, 0, 0).unwrap();
+    let end = Timestamp::from_ymd_hms(2024, 2, 20, 0, 0, 0, 0).unwrap();
+    let months: Vec<Timestamp> = Timestamp::months_between(start, end).collect();
+    assert_eq!(months, [start.start_of_month()]);
+    assert_eq!(Timestamp::months_between(start, start).count(), 0);
+    assert_eq!(Timestamp::months_between(end, start).count(), 0);
+    assert_eq!(Timestamp::months_between(Timestamp::MAX.start_of_month(), Timestamp::MAX).count(), 1);
+}
+
+#[test]
+fn test_humanize_relative_to() {
+    let now = Timestamp::from_ymd_hms(2024, 12, 14, 12, 0, 0, 0).unwrap();
+    let at = |secs: i64| now.checked_add_nanos(secs * 1_000_000_000).unwrap().humanize_relative_to(now);
+    assert_eq!(at(0), "just now");
+    assert_eq!(at(-44), "just now");
+    assert_eq!(at(44), "just now");
+    assert_eq!(at(-45), "1 minute ago");
+    assert_eq!(at(-180), "3 minutes ago");
+    assert_eq!(at(180), "in 3 minutes");
+    assert_eq!(at(-2_700), "1 hour ago");
+    assert_eq!(at(7_200), "in 2 hours");
+    assert_eq!(at(-79_200), "1 day ago");
+    assert_eq!(at(3 * 86_400), "in 3 days");
+    assert_eq!(at(-400 * 86_400), "400 days ago");
+}
+
+#[test]
+fn test_format_fixed() {
+    let ts = Timestamp::from_nanos(1_734_177_600_120_000_789);
+    assert_eq!(ts.format_fixed::<0>(), "2024-12-14T12:00:00Z");
+    assert_eq!(ts.format_fixed::<3>(), "2024-12-14T12:00:00.120Z");
+    assert_eq!(ts.format_fixed::<6>(), "2024-12-14T12:00:00.120000Z");
+    assert_eq!(ts.format_fixed::<9>(), "2024-12-14T12:00:00.120000789Z");
+    assert_eq!(ts.format_fixed::<3>(), ts.format_with_precision(Precision::Millisecond));
+}
+
+#[test]
+fn test_parse_with_offset() {
+    let utc = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    assert_eq!(Timestamp::parse_with_offset("2024-12-14T12:00:00Z").unwrap(), (utc, 0));
+    assert_eq!(Timestamp::parse_with_offset("2024-12-14T14:00:00+02:00").unwrap(), (utc, 120));
+    assert_eq!(Timestamp::parse_with_offset("2024-12-14T04:00:00-08:00").unwrap(), (utc, -480));
+    assert_eq!(Timestamp::parse_with_offset("2024-12-14T17:30:00.5+05:30").unwrap().1, 330);
+    assert_eq!(Timestamp::parse_with_offset("2024-12-14T12:00:00-00:00").unwrap(), (utc, 0));
+    assert!(Timestamp::parse_with_offset("2024-12-14T12:00:00").is_err());
+}
+
+#[test]
+fn test_now_truncated() {
+    let precision = universal_timestamp::get_clock_precision();
+    let before = Timestamp::now().truncate_to(Precision::Second);
+    let ts = Timestamp::now_truncated();
+    assert!(ts >= before && ts <= Timestamp::now());
+    if universal_timestamp::get_clock_precision() == precision {
+        assert_eq!(ts.truncate_to(precision), ts);
+    }
+}
+
+#[test]
+fn test_parse_with_formats() {
+    use universal_timestamp::Format;
+    let midnight = Timestamp::from_ymd_hms(2024, 12, 14, 0, 0, 0, 0).unwrap();
+    assert_eq!(Timestamp::parse_with_formats("2024-12-14", &[Format::Iso8601, Format::DateOnly]).unwrap(), midnight);
+    assert!(Timestamp::parse_with_formats("2024-12-14", &[Format::Iso8601, Format::Rfc3339]).is_err());
+    assert!(Timestamp::parse_with_formats("2024-12-14", &[]).is_err());
+
+    let noon = Timestamp::from_ymd_hms(2024, 12, 14, 12, 0, 0, 0).unwrap();
+    let all = [
+        Format::Iso8601,
+        Format::Rfc3339,
+        Format::Rfc2822,
+        Format::Iso8601Basic,
+        Format::DateOnly,
+        Format::UnixSeconds,
+    ];
+    for input in [
+        "2024-12-14T12:00:00Z",
+        "2024-12-14T14:00:00+02:00",
+        "Sat, 14 Dec 2024 12:00:00 +0000",
+        "20241214T120000Z",
+        "1734177600",
+    ] {
+        assert_eq!(Timestamp::parse_with_formats(input, &all).unwrap(), noon, "{}", input);
+    }
+    assert_eq!(Timestamp::parse_with_formats("-1500", &[Format::UnixMillis]).unwrap().as_nanos(), -1_500_000_000);
+    assert!(Timestamp::parse_with_formats("+1500", &[Format::UnixSeconds]).is_err());
+    assert!(Timestamp::parse_with_formats("2024-12-14 12:00:00", &all).is_err());
+}
+
+#[test]
+fn test_parse_with_formats_basic() {
+    use universal_timestamp::Format;
+    let parse = |s| Timestamp::parse_with_formats(s, &[Format::Iso8601Basic]);
+    assert_eq!(parse("20241214T120000.5Z").unwrap().format(true), "2024-12-14T12:00:00.5Z");
+    assert_eq!(parse("19691231T235959.999999999Z").unwrap().as_nanos(), -1);
+    assert_eq!(parse("20240230T000000Z").unwrap_err().kind(), ErrorKind::ParseFailure);
+    assert!(parse("20241214T120000").is_err());
+    assert!(parse("20241214T120000.Z").is_err());
+    assert!(parse("20241214T120000.1234567890Z").is_err());
+    assert!(parse("2024-12-14T12:00:00Z").is_err());
+}
+
+#[test]
+fn test_parse_date() {
+    let ts = Timestamp::parse_date("2024-12-14").unwrap();
+    assert_eq!(ts, Timestamp::from_ymd_hms(2024, 12, 14, 0, 0, 0, 0).unwrap());
+    assert_eq!(ts, ts.start_of_day());
+    assert_eq!(Timestamp::parse_date("2024-02-29").unwrap().format(false), "2024-02-29T00:00:00Z");
+    assert_eq!(Timestamp::parse_date("1969-12-31").unwrap().as_nanos(), -86_400_000_000_000);
+
+    assert_eq!(Timestamp::parse_date("2023-02-29").unwrap_err().kind(), ErrorKind::ParseFailure);
+    assert_eq!(Timestamp::parse_date("2024-02-30").unwrap_err().kind(), ErrorKind::ParseFailure);
+    for bad in ["2024-12-14T12:00:00Z", "2024-12-14T00:00", "2024-12-14Z", "2024-12-14 ", "2024-1-14", "+024-12-14"] {
+        assert_eq!(Timestamp::parse_date(bad).unwrap_err().kind(), ErrorKind::InvalidFormat, "{}", bad);
+    }
+}
+
+#[test]
+fn test_iso_weeks_in_year() {
+    assert_eq!(calendar::iso_weeks_in_year(2020), 53);
+    assert_eq!(calendar::iso_weeks_in_year(2021), 52);
+    assert_eq!(calendar::iso_weeks_in_year(2015), 53);
+    assert_eq!(calendar::iso_weeks_in_year(2024), 52);
+    assert_eq!(calendar::iso_weeks_in_year(2026), 53);
+    assert_eq!(calendar::iso_weeks_in_year(2004), 53);
+    // The last ISO week of a 53-week year is its own week 53.
+    let dec31 = Timestamp::from_ymd_hms(2020, 12, 31, 0, 0, 0, 0).unwrap();
+    assert_eq!(dec31.to_iso_week(), (2020, 53, 4));
+}
+
+#[test]
+fn test_calendar_days_between() {
+    let late = Timestamp::from_ymd_hms(2024, 12, 14, 23, 0, 0, 0).unwrap();
+    let early = Timestamp::from_ymd_hms(2024, 12, 15, 1, 0, 0, 0).unwrap();
+    assert_eq!(late.calendar_days_between(early), 1);
+    assert_eq!(early.calendar_days_between(late), -1);
+    // Dividing the two-hour gap by 86,400 seconds gives zero.
+    assert_eq!((early - late).as_secs() / 86_400, 0);
+
+    let midnight = late.start_of_day();
+    assert_eq!(midnight.calendar_days_between(late), 0);
+    assert_eq!(midnight.calendar_days_between(late.end_of_day()), 0);
+    let deadline = Timestamp::from_ymd_hms(2025, 1, 1, 0, 0, 0, 0).unwrap();
+    assert_eq!(late.calendar_days_between(deadline), 18);
+    assert_eq!(Timestamp::from_nanos(-1).calendar_days_between(Timestamp::EPOCH), 1);
+}
+
+#[test]
+fn test_try_now() {
+    let ts = Timestamp::try_now().unwrap();
+    assert!(ts.as_nanos() > 0);
+    assert!(ts.year() >= 2024);
+}
+
+#[test]
+fn test_raw_nanos() {
+    use std::mem::{align_of, size_of};
+    assert_eq!(size_of::<Timestamp>(), size_of::<i64>());
+    assert_eq!(align_of::<Timestamp>(), align_of::<i64>());
+    assert_eq!(size_of::<Option<Timestamp>>(), size_of::<Option<i64>>());
+
+    for nanos in [i64::MIN, -1, 0, 1_734_177_600_123_456_789, i64::MAX] {
+        let ts = Timestamp::from_raw_nanos(nanos);
+        assert_eq!(ts, Timestamp::from_nanos(nanos));
+        assert_eq!(ts.into_raw_nanos(), ts.as_nanos());
+    }
+}
+
+#[test]
+fn test_nanos_slice_reinterpretation() {
+    let column = [i64::MIN, -1, 0, 1_734_177_600_123_456_789, i64::MAX];
+    let timestamps = Timestamp::from_nanos_slice(&column);
+    assert_eq!(timestamps.len(), column.len());
+    assert_eq!(timestamps.as_ptr() as usize, column.as_ptr() as usize);
+    for (ts, nanos) in timestamps.iter().zip(column) {
+        assert_eq!(ts.as_nanos(), nanos);
+    }
+    assert_eq!(timestamps[3].format(true), "2024-12-14T12:00:00.123456789Z");
+
+    let back = Timestamp::as_nanos_slice(timestamps);
+    assert_eq!(back, column);
+    assert!(Timestamp::from_nanos_slice(&[]).is_empty());
+}
+
+#[test]
+fn test_parse_error_position() {
+    let bad_month = Timestamp::parse("2024-13-14T12:00:00Z").unwrap_err();
+    assert_eq!(bad_month.position(), Some(5));
+
+    let bad_offset = Timestamp::parse("2024-12-14T12:00:00+0x:00").unwrap_err();
+    assert_eq!(bad_offset.position(), Some(19));
+
+    let bad_digit = Timestamp::parse("2024-12-1xT12:00:00Z").unwrap_err();
+    assert_eq!(bad_digit.position(), Some(9));
+
+    let truncated = Timestamp::parse("2024-12-14T12:00").unwrap_err();
+    assert_eq!(truncated.position(), Some(16));
+
+    let nul = Timestamp::parse("2024-12-14\0T12:00:00Z").unwrap_err();
+    assert_eq!(nul.position(), Some(10));
+
+    assert_eq!(Timestamp::parse_date("2024-13-01").unwrap_err().position(), None);
+}
+
+#[test]
+fn test_clock_domains_compose() {
+    let parsed = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    let wall = Timestamp::now();
+    let bumped = Timestamp::now_monotonic();
+    assert!(parsed < wall && parsed < bumped);
+    assert!(bumped.diff(wall) < Duration::from_secs(60));
+
+    let start = Monotonic::now();
+    let later = Monotonic::now();
+    assert!(later >= start);
+    assert!(later.duration_since(start) < Duration::from_secs(60));
+}
+
+#[test]
+fn test_format_parse_basic() {
+    let ts = Timestamp::from_ymd_hms(2024, 12, 14, 12, 0, 0, 0).unwrap();
+    assert_eq!(ts.format_basic(false), "20241214T120000Z");
+    assert_eq!(Timestamp::parse_basic("20241214T120000Z").unwrap(), ts);
+
+    let precise = Timestamp::from_nanos(1_734_177_600_123_456_789);
+    let basic = precise.format_basic(true);
+    assert_eq!(basic, "20241214T120000.123456789Z");
+    assert_eq!(Timestamp::parse_basic(&basic).unwrap(), precise);
+    assert_eq!(Timestamp::parse_basic(&basic).unwrap(), Timestamp::parse(&precise.format(true)).unwrap());
+
+    assert!(Timestamp::parse_basic("2024-12-14T12:00:00Z").is_err());
+    assert!(Timestamp::parse_basic("20241214T120000").is_err());
+}
+
+#[test]
+fn test_format_parse_filename() {
+    let ts = Timestamp::from_nanos(1_734_177_600_123_456_789);
+    let name = ts.format_filename();
+    assert_eq!(name, "2024-12-14_12-00-00");
+    assert!(!name.contains(':') && !name.contains('/') && !name.contains('\\'));
+    assert_eq!(Timestamp::parse_filename(&name).unwrap(), ts.truncate_to(Precision::Second));
+
+    assert!(Timestamp::parse_filename("2024-12-14T12:00:00").is_err());
+    assert!(Timestamp::parse_filename("2024-12-14_12-00").is_err());
+    assert!(Timestamp::parse_filename("2024-12-14_25-00-00").is_err());
+}
+
+#[test]
+fn test_is_plausible() {
+    let nanos = 1_734_177_600_123_456_789;
+    assert!(Timestamp::from_nanos(nanos).is_plausible());
+    assert_eq!(Timestamp::from_nanos_checked(nanos), Some(Timestamp::from_nanos(nanos)));
+
+    // Milliseconds misread as nanoseconds: 1970-01-01T00:28:54Z.
+    let millis = nanos / 1_000_000;
+    assert!(!Timestamp::from_nanos(millis).is_plausible());
+    assert_eq!(Timestamp::from_nanos_checked(millis), None);
+
+    assert_eq!(Timestamp::PLAUSIBLE_MIN.format(false), "1971-01-01T00:00:00Z");
+    assert_eq!(Timestamp::PLAUSIBLE_MAX.format(false), "2200-01-01T00:00:00Z");
+    assert!(Timestamp::PLAUSIBLE_MIN.is_plausible());
+    assert!(!Timestamp::PLAUSIBLE_MAX.is_plausible());
+    assert!(!Timestamp::MAX.is_plausible());
+}
+
+#[test]
+fn test_next_previous_weekday() {
+    // 2024-12-14 is a Saturday.
+    let sat = Timestamp::from_ymd_hms(2024, 12, 14, 12, 30, 0, 0).unwrap();
+
+    let mon = sat.next_weekday(Weekday::Monday);
+    assert_eq!(mon, Timestamp::from_ymd_hms(2024, 12, 16, 12, 30, 0, 0).unwrap());
+    assert_eq!(mon.weekday(), Weekday::Monday);
+    assert_eq!(sat.next_weekday(Weekday::Friday).day(), 20);
+
+    let thu = sat.previous_weekday(Weekday::Thursday);
+    assert_eq!(thu, Timestamp::from_ymd_hms(2024, 12, 12, 12, 30, 0, 0).unwrap());
+    assert_eq!(sat.previous_weekday(Weekday::Sunday).day(), 8);
+
+    assert_eq!(sat.next_weekday(Weekday::Saturday), sat);
+    assert_eq!(sat.previous_weekday(Weekday::Saturday), sat);
+    assert_eq!(Timestamp::MAX.next_weekday(Weekday::Monday), Timestamp::MAX);
+}
+
+#[test]
+fn test_japanese_era_transitions() {
+    let transitions = calendar::japanese_era_transitions();
+    assert_eq!(transitions.len(), 5);
+    assert!(transitions.windows(2).all(|w| w[0].0 < w[1].0));
+
+    for &(start, era) in transitions {
+        assert_eq!(start.to_japanese_era().unwrap(), (era, 1));
+    }
+    assert!((transitions[0].0 - Duration::from_nanos(1)).to_japanese_era().is_err());
+
+    let ts = Timestamp::parse("1995-06-01T00:00:00Z").unwrap();
+    let index = transitions.partition_point(|&(start, _)| start <= ts) - 1;
+    assert_eq!(transitions[index].1, JapaneseEra::Heisei);
+    assert_eq!(ts.to_japanese_era().unwrap().0, transitions[index].1);
+}
+
+#[test]
+fn test_month_weekday_names() {
+    use calendar::NameStyle;
+    assert_eq!(calendar::month_name(1, NameStyle::Full).unwrap(), "January");
+    assert_eq!(calendar::month_name(9, NameStyle::Abbreviated).unwrap(), "Sep");
+    assert_eq!(calendar::month_name(12, NameStyle::Full).unwrap(), "December");
+    assert_eq!(calendar::month_name(0, NameStyle::Full).unwrap_err().kind(), ErrorKind::OutOfRange);
+    assert!(calendar::month_name(13, NameStyle::Abbreviated).is_err());
+
+    assert_eq!(calendar::weekday_name(Weekday::Wednesday, NameStyle::Full), "Wednesday");
+    assert_eq!(calendar::weekday_name(Weekday::Sunday, NameStyle::Abbreviated), "Sun");
+    let ts = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    assert_eq!(calendar::weekday_name(ts.weekday(), NameStyle::Full), "Saturday");
+}
+
+#[test]
+fn test_format_parse_ordinal() {
+    let ts = Timestamp::parse("2024-12-14T00:00:00Z").unwrap();
+    assert_eq!(ts.format_ordinal(), "2024-349");
+    assert_eq!(Timestamp::parse_ordinal("2024-349").unwrap(), ts);
+
+    let last = Timestamp::parse("2024-12-31T00:00:00Z").unwrap();
+    assert_eq!(last.format_ordinal(), "2024-366");
+    assert_eq!(Timestamp::parse_ordinal("2024-366").unwrap(), last);
+
+    let first = Timestamp::parse("2023-01-01T00:00:00Z").unwrap();
+    assert_eq!(first.format_ordinal(), "2023-001");
+    assert_eq!(Timestamp::parse_ordinal("2023-001").unwrap(), first);
+
+    assert_eq!(Timestamp::parse_ordinal("2023-366").unwrap_err().kind(), ErrorKind::ParseFailure);
+    assert!(Timestamp::parse_ordinal("2023-000").is_err());
+    assert!(Timestamp::parse_ordinal("2023-49").is_err());
+}
+
+#[test]
+fn test_from_ymd_hms_offset() {
+    let utc = Timestamp::from_ymd_hms(2024, 12, 14, 12, 0, 0, 0).unwrap();
+    assert_eq!(Timestamp::from_ymd_hms_offset(2024, 12, 14, 14, 0, 0, 0, 120).unwrap(), utc);
+    assert_eq!(Timestamp::from_ymd_hms_offset(2024, 12, 14, 4, 0, 0, 0, -480).unwrap(), utc);
+    assert_eq!(Timestamp::from_ymd_hms_offset(2024, 12, 14, 12, 0, 0, 0, 0).unwrap(), utc);
+
+    let (parsed, offset) = Timestamp::parse_with_offset("2024-12-15T11:59:00+23:59").unwrap();
+    assert_eq!(Timestamp::from_ymd_hms_offset(2024, 12, 15, 11, 59, 0, 0, offset).unwrap(), parsed);
+
+    let too_far = Timestamp::from_ymd_hms_offset(2024, 12, 14, 12, 0, 0, 0, 24 * 60);
+    assert_eq!(too_far.unwrap_err().kind(), ErrorKind::OutOfRange);
+    assert!(Timestamp::from_ymd_hms_offset(2024, 12, 14, 12, 0, 0, 0, -24 * 60).is_err());
+    assert!(Timestamp::from_ymd_hms_offset(2024, 2, 30, 12, 0, 0, 0, 60).is_err());
+}
+
+#[test]
+fn test_debug_format() {
+    let ts = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    assert_eq!(format!("{:?}", ts), "Timestamp(2024-12-14T12:00:00.000000000Z)");
+    assert_eq!(format!("{}", ts), "2024-12-14T12:00:00Z");
+
+    let precise = Timestamp::from_nanos(1_734_177_600_000_000_500);
+    assert_eq!(format!("{:?}", precise), "Timestamp(2024-12-14T12:00:00.000000500Z)");
+    assert_eq!(format!("{:?}", Some(Timestamp::EPOCH)), "Some(Timestamp(1970-01-01T00:00:00.000000000Z))");
+}
+
+#[test]
+fn test_parse_flexible() {
+    let expected = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    assert_eq!(Timestamp::parse_flexible("2024-12-14T12:00:00Z").unwrap(), expected);
+    assert_eq!(Timestamp::parse_flexible("2024-12-14T14:00:00+02:00").unwrap(), expected);
+    assert_eq!(Timestamp::parse_flexible("2024-12-14T12:00:00").unwrap(), expected);
+
+    let precise = Timestamp::parse_flexible("2024-12-14T04:00:00.5-08:00").unwrap();
+    assert_eq!(precise, expected + Duration::from_millis(500));
+    assert_eq!(Timestamp::parse_flexible("2024-12-14T12:00:00.5").unwrap(), precise);
+
+    assert!(Timestamp::parse_flexible("2024-12-14 12:00:00").is_err());
+    assert!(Timestamp::parse_flexible("2024-12-14T12:00:00z").is_err());
+    assert!(Timestamp::parse_flexible("2024-12-14T12:00:00+2:00").is_err());
+}
+
+#[test]
+fn test_duration_since_epoch() {
+    let ts = Timestamp::from_nanos(1_734_177_600_123_456_789);
+    assert_eq!(ts.duration_since_epoch(), Some(Duration::new(1_734_177_600, 123_456_789)));
+    assert_eq!(Timestamp::EPOCH.duration_since_epoch(), Some(Duration::ZERO));
+    assert_eq!(Timestamp::from_nanos(-1).duration_since_epoch(), None);
+    assert_eq!(Timestamp::MIN.duration_since_epoch(), None);
+}
+
+#[test]
+fn test_protobuf_split() {
+    let ts = Timestamp::from_nanos(1_734_177_600_123_456_789);
+    assert_eq!(ts.as_protobuf(), (1_734_177_600, 123_456_789));
+    assert_eq!(Timestamp::from_protobuf(1_734_177_600, 123_456_789).unwrap(), ts);
+
+    let before = Timestamp::from_nanos(-500_000_000);
+    assert_eq!(before.as_protobuf(), (-1, 500_000_000));
+    assert_eq!(Timestamp::from_protobuf(-1, 500_000_000).unwrap(), before);
+
+    let (seconds, nanos) = Timestamp::MIN.as_protobuf();
+    assert!(nanos >= 0);
+    assert_eq!(Timestamp::from_protobuf(seconds, nanos).unwrap(), Timestamp::MIN);
+
+    assert!(Timestamp::from_protobuf(0, -1).is_err());
+    assert!(Timestamp::from_protobuf(0, 1_000_000_000).is_err());
+    assert_eq!(Timestamp::from_protobuf(i64::MAX, 0).unwrap_err().kind(), ErrorKind::OutOfRange);
+}
+
+#[test]
+fn test_format_rfc3339_designator() {
+    let ts = Timestamp::from_ymd_hms(2024, 12, 14, 12, 0, 0, 0).unwrap();
+    assert_eq!(ts.format_rfc3339(true, false), "2024-12-14T12:00:00Z");
+    assert_eq!(ts.format_rfc3339(false, false), "2024-12-14T12:00:00+00:00");
+
+    let precise = Timestamp::from_nanos(1_734_177_600_123_456_789);
+    assert_eq!(precise.format_rfc3339(true, true), "2024-12-14T12:00:00.123456789Z");
+    assert_eq!(precise.format_rfc3339(false, true), "2024-12-14T12:00:00.123456789+00:00");
+    assert_eq!(precise.format_rfc3339(false, false), "2024-12-14T12:00:00+00:00");
+
+    for zulu in [true, false] {
+        let s = precise.format_rfc3339(zulu, true);
+        assert_eq!(Timestamp::parse_rfc3339(&s).unwrap(), precise);
+    }
+}
+
+#[test]
+fn test_checked_duration_since() {
+    let earlier = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    let later = earlier + Duration::from_millis(1_500);
+    assert_eq!(later.checked_duration_since(earlier), Some(Duration::from_millis(1_500)));
+    assert_eq!(earlier.checked_duration_since(later), None);
+    assert_eq!(earlier.checked_duration_since(earlier), Some(Duration::ZERO));
+    assert_eq!(
+        Timestamp::MAX.checked_duration_since(Timestamp::MIN),
+        Some(Duration::from_nanos(u64::MAX))
+    );
+}
+
+#[test]
+fn test_format_smart() {
+    let midnight = Timestamp::parse("2024-12-14T00:00:00Z").unwrap();
+    assert_eq!(midnight.format_smart(), "2024-12-14");
+    assert_eq!(Timestamp::parse_date(&midnight.format_smart()).unwrap(), midnight);
+    assert_eq!(Timestamp::EPOCH.format_smart(), "1970-01-01");
+
+    let noon = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    assert_eq!(noon.format_smart(), "2024-12-14T12:00:00Z");
+    let just_after = midnight + Duration::from_nanos(1);
+    assert_eq!(just_after.format_smart(), "2024-12-14T00:00:00.000000001Z");
+}
+
+#[test]
+fn test_parse_allow_leap_second() {
+    assert!(Timestamp::parse("2016-12-31T23:59:60Z").is_err());
+
+    let leap = Timestamp::parse_allow_leap_second("2016-12-31T23:59:60Z").unwrap();
+    assert_eq!(leap, Timestamp::parse("2017-01-01T00:00:00Z").unwrap());
+    assert!(leap > Timestamp::parse("2016-12-31T23:59:59.999999999Z").unwrap());
+
+    let fractional = Timestamp::parse_allow_leap_second("2016-12-31T23:59:60.5Z").unwrap();
+    assert_eq!(fractional, leap + Duration::from_millis(500));
+
+    let ordinary = "2024-12-14T12:00:00Z";
+    assert_eq!(Timestamp::parse_allow_leap_second(ordinary).unwrap(), Timestamp::parse(ordinary).unwrap());
+    assert!(Timestamp::parse_allow_leap_second("2016-12-31T12:00:60Z").is_err());
+    assert!(Timestamp::parse_allow_leap_second("2016-12-31T23:59:60").is_err());
+}
+
+#[test]
+fn test_format_at_offset() {
+    let ts = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    assert_eq!(ts.format_at_offset(330, false).unwrap(), "2024-12-14T17:30:00+05:30");
+    assert_eq!(ts.format_at_offset(-480, false).unwrap(), "2024-12-14T04:00:00-08:00");
+    assert_eq!(ts.format_at_offset(0, false).unwrap(), "2024-12-14T12:00:00+00:00");
+    assert_eq!(ts.format_at_offset(-30, false).unwrap(), "2024-12-14T11:30:00-00:30");
+
+    let late = Timestamp::from_nanos(1_734_220_800_000_000_000 - 500_000_000);
+    assert_eq!(late.format_at_offset(60, true).unwrap(), "2024-12-15T00:59:59.5+01:00");
+    assert_eq!(late.format_at_offset(-600, true).unwrap(), "2024-12-14T13:59:59.5-10:00");
+
+    for offset in [330, -480, 0, -30, 60] {
+        let s = late.format_at_offset(offset, true).unwrap();
+        assert_eq!(Timestamp::parse_with_offset(&s).unwrap(), (late, offset));
+    }
+    assert_eq!(Timestamp::MAX.format_at_offset(60, false).unwrap(), "2262-04-12T00:47:16+01:00");
+
+    // Offsets must stay within a day, as in from_ymd_hms_offset
+    assert_eq!(ts.format_at_offset(1439, false).unwrap(), "2024-12-15T11:59:00+23:59");
+    for offset in [1440, -1440, i32::MAX, i32::MIN] {
+        assert_eq!(ts.format_at_offset(offset, false).unwrap_err().kind(), ErrorKind::OutOfRange);
+    }
+}
+
+#[test]
+fn test_subsec() {
+    let ts = Timestamp::from_nanos(1_734_177_600_123_456_789);
+    assert_eq!(ts.subsec_nanos(), 123_456_789);
+    assert_eq!(ts.subsec_micros(), 123_456);
+    assert_eq!(ts.subsec_millis(), 123);
+    assert_eq!(ts.subsec_nanos(), ts.nanosecond());
+
+    let before = Timestamp::from_nanos(-250_000_001);
+    assert_eq!(before.subsec_nanos(), 749_999_999);
+    assert_eq!(before.subsec_micros(), 749_999);
+    assert_eq!(before.subsec_millis(), 749);
+    assert_eq!(Timestamp::from_nanos(-1_000_000_000).subsec_nanos(), 0);
+    assert!(Timestamp::MIN.subsec_nanos() < 1_000_000_000);
+}
+
+#[test]
+fn test_timestamp_builder() {
+    let date = TimestampBuilder::new().year(2024).month(12).day(14).build().unwrap();
+    assert_eq!(date, Timestamp::parse("2024-12-14T00:00:00Z").unwrap());
+
+    let full = TimestampBuilder::default()
+        .year(2024)
+        .month(12)
+        .day(14)
+        .hour(12)
+        .minute(30)
+        .second(15)
+        .nanosecond(500)
+        .build()
+        .unwrap();
+    assert_eq!(full, Timestamp::from_ymd_hms(2024, 12, 14, 12, 30, 15, 500).unwrap());
+    assert_eq!(TimestampBuilder::new().build().unwrap(), Timestamp::EPOCH);
+
+    let invalid = TimestampBuilder::new().year(2023).month(2).day(29).build();
+    assert_eq!(invalid.unwrap_err().kind(), ErrorKind::ParseFailure);
+    assert!(TimestampBuilder::new().hour(24).build().is_err());
+}
+
+#[test]
+fn test_same_day_month_year() {
+    let morning = Timestamp::parse("2024-12-14T00:00:01Z").unwrap();
+    let evening = Timestamp::parse("2024-12-14T23:59:59Z").unwrap();
+    assert!(morning.same_day(evening));
+
+    let before_midnight = Timestamp::parse("2024-12-13T23:59:59Z").unwrap();
+    assert!(!before_midnight.same_day(morning));
+    assert!(before_midnight.diff(morning) < Duration::from_secs(60));
+    assert!(before_midnight.same_month(morning));
+
+    let later = Timestamp::parse("2024-12-31T12:00:00Z").unwrap();
+    assert!(later.same_month(morning) && !later.same_day(morning));
+    let next_year = Timestamp::parse("2025-12-14T00:00:01Z").unwrap();
+    assert!(!next_year.same_month(morning) && !next_year.same_year(morning));
+    assert!(Timestamp::parse("2024-01-01T00:00:00Z").unwrap().same_year(later));
+}
+
+#[test]
+fn test_calendar_french_republican() {
+    // 1 January 1800 fell in year VIII, which began on 23 September 1799.
+    assert_eq!(calendar::gregorian_to_french_republican(1800), 8);
+    assert_eq!(calendar::french_republican_to_gregorian(8), 1800);
+    assert_eq!(calendar::gregorian_to_french_republican(1793), 1);
+    assert_eq!(calendar::gregorian_to_french_republican(1700), -92);
+    assert_eq!(calendar::french_republican_to_gregorian(calendar::gregorian_to_french_republican(2024)), 2024);
+}
+
+#[test]
+#[cfg(target_has_atomic = "64")]
+fn test_coarse_clock() {
+    use universal_timestamp::CoarseClock;
+
+    let clock = CoarseClock::new();
+    let cached = clock.get();
+    assert!(cached.is_plausible());
+
+    std::thread::sleep(Duration::from_millis(2));
+    assert_eq!(clock.get(), cached);
+    assert_eq!(clock.get(), cached);
+
+    let refreshed = clock.refresh();
+    assert!(refreshed > cached);
+    assert_eq!(clock.get(), refreshed);
+}
+
+#[test]
+fn test_parse_unix() {
+    let expected = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    assert_eq!(Timestamp::parse_unix("1734177600", Unit::Seconds).unwrap(), expected);
+    assert_eq!(Timestamp::parse_unix("1734177600000", Unit::Millis).unwrap(), expected);
+    assert_eq!(Timestamp::parse_unix("1734177600000000", Unit::Micros).unwrap(), expected);
+    assert_eq!(Timestamp::parse_unix("1734177600000000000", Unit::Nanos).unwrap(), expected);
+    assert_eq!(Timestamp::parse_unix("-1", Unit::Seconds).unwrap(), Timestamp::from_nanos(-1_000_000_000));
+
+    let overflow = Timestamp::parse_unix("9223372037", Unit::Seconds).unwrap_err();
+    assert_eq!(overflow.kind(), ErrorKind::OutOfRange);
+    let too_long = Timestamp::parse_unix("9223372036854775808", Unit::Nanos).unwrap_err();
+    assert_eq!(too_long.kind(), ErrorKind::OutOfRange);
+
+    for bad in ["", "-", "+1", " 1", "1.5", "1e9", "abc"] {
+        assert_eq!(Timestamp::parse_unix(bad, Unit::Seconds).unwrap_err().kind(), ErrorKind::InvalidFormat);
+    }
+}
+
+const LAUNCH: Timestamp = Timestamp::from_nanos_const(1_734_177_600_000_000_000);
+const SHUTDOWN: Timestamp = Timestamp::from_nanos_const(1_734_264_000_000_000_000);
+const _: () = assert!(matches!(Timestamp::const_cmp(LAUNCH, SHUTDOWN), std::cmp::Ordering::Less));
+
+#[test]
+fn test_const_cmp() {
+    use std::cmp::Ordering;
+    assert_eq!(Timestamp::const_cmp(LAUNCH, SHUTDOWN), Ordering::Less);
+    assert_eq!(Timestamp::const_cmp(SHUTDOWN, LAUNCH), Ordering::Greater);
+    assert_eq!(Timestamp::const_cmp(LAUNCH, LAUNCH), Ordering::Equal);
+    for (a, b) in [(Timestamp::MIN, Timestamp::MAX), (Timestamp::EPOCH, Timestamp::from_nanos(-1))] {
+        assert_eq!(Timestamp::const_cmp(a, b), a.cmp(&b));
+    }
+}
+
+#[test]
+fn test_calendar_diff() {
+    let delta = |years, months, days| CalendarDelta { years, months, days };
+    let ts = |s| Timestamp::parse(s).unwrap();
+
+    let start = ts("2023-03-15T12:00:00Z");
+    assert_eq!(start.calendar_diff(ts("2024-03-15T12:00:00Z")), delta(1, 0, 0));
+    assert_eq!(start.calendar_diff(ts("2024-03-14T12:00:00Z")), delta(0, 11, 28));
+    // The final day only completes at 12:00.
+    assert_eq!(start.calendar_diff(ts("2024-03-15T11:59:59Z")), delta(0, 11, 28));
+    assert_eq!(ts("2024-03-15T12:00:00Z").calendar_diff(start), delta(-1, 0, 0));
+
+    // Crossing the 2024 leap day: 28 days to March 1 in a common year, 29 in a leap year.
+    assert_eq!(ts("2023-02-01T00:00:00Z").calendar_diff(ts("2023-03-01T00:00:00Z")), delta(0, 1, 0));
+    assert_eq!(ts("2024-02-10T00:00:00Z").calendar_diff(ts("2024-03-09T00:00:00Z")), delta(0, 0, 28));
+    assert_eq!(ts("2024-02-29T00:00:00Z").calendar_diff(ts("2025-02-28T00:00:00Z")), delta(1, 0, 0));
+    assert_eq!(ts("2024-01-31T00:00:00Z").calendar_diff(ts("2024-02-29T00:00:00Z")), delta(0, 1, 0));
+    assert_eq!(ts("2024-01-31T00:00:00Z").calendar_diff(ts("2024-03-01T00:00:00Z")), delta(0, 1, 1));
+
+    assert_eq!(start.calendar_diff(start), CalendarDelta::default());
+}
+
+#[test]
+fn test_parse_prefix() {
+    let expected = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    assert_eq!(Timestamp::parse_prefix("2024-12-14T12:00:00Z rest").unwrap(), (expected, " rest"));
+    assert_eq!(Timestamp::parse_prefix("2024-12-14T12:00:00Z").unwrap(), (expected, ""));
+
+    let (precise, rest) = Timestamp::parse_prefix("2024-12-14T12:00:00.25Z,next").unwrap();
+    assert_eq!(precise, expected + Duration::from_millis(250));
+    assert_eq!(rest, ",next");
+
+    let (first, rest) = Timestamp::parse_prefix("2024-12-14T12:00:00Z2024-12-15T12:00:00Z").unwrap();
+    assert_eq!(first, expected);
+    assert_eq!(Timestamp::parse_prefix(rest).unwrap().0, expected + Duration::from_secs(86_400));
+
+    let missing_zone = Timestamp::parse_prefix("2024-12-14T12:00:00 rest").unwrap_err();
+    assert_eq!(missing_zone.position(), Some(19));
+    assert_eq!(Timestamp::parse_prefix("2024-13-14T12:00:00Z rest").unwrap_err().position(), Some(5));
+    assert!(Timestamp::parse_prefix("rest").is_err());
+}
+
+#[test]
+fn test_snap_to_minutes() {
+    let ts = Timestamp::parse("2024-12-14T12:07:42.5Z").unwrap();
+    let at = |s| Timestamp::parse(s).unwrap();
+    assert_eq!(ts.snap_to_minutes(5, SnapMode::Floor).unwrap(), at("2024-12-14T12:05:00Z"));
+    assert_eq!(ts.snap_to_minutes(5, SnapMode::Ceil).unwrap(), at("2024-12-14T12:10:00Z"));
+    assert_eq!(ts.snap_to_minutes(5, SnapMode::Nearest).unwrap(), at("2024-12-14T12:10:00Z"));
+    assert_eq!(ts.snap_to_minutes(15, SnapMode::Nearest).unwrap(), at("2024-12-14T12:15:00Z"));
+    assert_eq!(ts.snap_to_minutes(60, SnapMode::Ceil).unwrap(), at("2024-12-14T13:00:00Z"));
+
+    let boundary = at("2024-12-14T12:05:00Z");
+    assert_eq!(boundary.snap_to_minutes(5, SnapMode::Ceil).unwrap(), boundary);
+    let tie = at("2024-12-14T12:07:30Z");
+    assert_eq!(tie.snap_to_minutes(5, SnapMode::Nearest).unwrap(), at("2024-12-14T12:10:00Z"));
+
+    let before_epoch = at("1969-12-31T23:58:00Z");
+    assert_eq!(before_epoch.snap_to_minutes(5, SnapMode::Floor).unwrap(), at("1969-12-31T23:55:00Z"));
+
+    assert_eq!(ts.snap_to_minutes(7, SnapMode::Floor).unwrap_err().kind(), ErrorKind::OutOfRange);
+    assert!(ts.snap_to_minutes(0, SnapMode::Floor).is_err());
+    assert_eq!(Timestamp::MAX.snap_to_minutes(5, SnapMode::Ceil).unwrap_err().kind(), ErrorKind::Overflow);
+}
+
+#[test]
+fn test_guess_unix_unit() {
+    let expected = Timestamp::parse("2024-12-14T12:00:00Z").unwrap();
+    let bands = [
+        (1_734_177_600, Unit::Seconds),
+        (1_734_177_600_000, Unit::Millis),
+        (1_734_177_600_000_000, Unit::Micros),
+        (1_734_177_600_000_000_000, Unit::Nanos),
+    ];
+    for (value, unit) in bands {
+        assert_eq!(Timestamp::guess_unix_unit(value), unit);
+        assert_eq!(Timestamp::from_unix_auto(value).unwrap(), expected);
+    }
+
+    assert_eq!(Timestamp::guess_unix_unit(-1_734_177_600_000), Unit::Millis);
+    assert_eq!(Timestamp::guess_unix_unit(0), Unit::Seconds);
+    assert_eq!(Timestamp::guess_unix_unit(i64::MIN), Unit::Nanos);
+    assert_eq!(Timestamp::from_unix_auto(99_999_999_999).unwrap_err().kind(), ErrorKind::OutOfRange);
+    for value in [9_300_000_000_000, 9_300_000_000_000_000] {
+        assert_eq!(Timestamp::from_unix_auto(value).unwrap_err().kind(), ErrorKind::OutOfRange);
+    }
+}
+
+#[test]
+fn test_add_business_days() {
+    let at = |s| Timestamp::parse(s).unwrap();
+    let friday = at("2024-12-13T09:30:00Z");
+    let monday = at("2024-12-16T09:30:00Z");
+    assert_eq!(friday.add_business_days(1), monday);
+    assert_eq!(monday.add_business_days(-1), friday);
+    assert_eq!(friday.add_business_days(0), friday);
+
+    assert_eq!(monday.add_business_days(4), at("2024-12-20T09:30:00Z"));
+    assert_eq!(monday.add_business_days(5), at("2024-12-23T09:30:00Z"));
+    assert_eq!(friday.add_business_days(6), at("2024-12-23T09:30:00Z"));
+    assert_eq!(monday.add_business_days(-6), at("2024-12-06T09:30:00Z"));
+
+    let saturday = at("2024-12-14T09:30:00Z");
+    assert_eq!(saturday.add_business_days(1), monday);
+    assert_eq!(saturday.add_business_days(5), at("2024-12-20T09:30:00Z"));
+    assert_eq!(saturday.add_business_days(-1), friday);
+    assert_eq!(saturday.add_business_days(0), saturday);
+
+    for n in -12..=12 {
+        let ts = monday.add_business_days(n);
+        assert!(ts.weekday().number_from_monday() <= 5);
+        assert_eq!(ts.add_business_days(-n), monday);
+    }
+    assert_eq!(monday.add_business_days(i64::MAX), Timestamp::MAX);
+    assert_eq!(monday.add_business_days(i64::MIN), Timestamp::MIN);
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use universal_timestamp::Timestamp;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Event {
+    at: Timestamp,
+    #[serde(with = "universal_timestamp::serde_nanos")]
+    raw: Timestamp,
+}
+
+#[test]
+fn test_serde_iso_round_trip() {
+    let ts = Timestamp::from_nanos(1734147201123456789);
+    let json = serde_json::to_string(&ts).unwrap();
+    assert_eq!(json, "\"2024-12-14T03:33:21.123456789Z\"");
+    assert_eq!(serde_json::from_str::<Timestamp>(&json).unwrap(), ts);
+}
+
+#[test]
+fn test_serde_nanos_round_trip() {
+    let ts = Timestamp::from_nanos(-1_500);
+    let event = Event { at: ts, raw: ts };
+    let json = serde_json::to_string(&event).unwrap();
+    assert_eq!(json, r#"{"at":"1969-12-31T23:59:59.9999985Z","raw":-1500}"#);
+    assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+}
+
+#[test]
+fn test_serde_malformed() {
+    assert!(serde_json::from_str::<Timestamp>("\"2024-12-14 12:00:00\"").is_err());
+    assert!(serde_json::from_str::<Timestamp>("42").is_err());
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Record {
+    #[serde(with = "universal_timestamp::serde_compact")]
+    at: Timestamp,
+}
+
+#[test]
+fn test_serde_compact_bincode_round_trip() {
+    for nanos in [1734147201123456789, -1_500, i64::MIN, i64::MAX] {
+        let record = Record { at: Timestamp::from_nanos(nanos) };
+        let bytes = bincode::serialize(&record).unwrap();
+        assert_eq!(bytes, nanos.to_le_bytes());
+        assert_eq!(bincode::deserialize::<Record>(&bytes).unwrap(), record);
+    }
+    // The default representation stays the ISO string.
+    let ts = Timestamp::from_nanos(1734147201123456789);
+    assert!(bincode::serialize(&ts).unwrap().len() > 8);
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Published {
+    #[serde(with = "universal_timestamp::serde_rfc3339_offset")]
+    at: Timestamp,
+}
+
+#[test]
+fn test_serde_rfc3339_offset_round_trip() {
+    let event = Published { at: Timestamp::from_nanos(1734147201123456789) };
+    let json = serde_json::to_string(&event).unwrap();
+    assert_eq!(json, r#"{"at":"2024-12-14T03:33:21.123456789+00:00"}"#);
+    assert_eq!(serde_json::from_str::<Published>(&json).unwrap(), event);
+
+    let zulu = r#"{"at":"2024-12-14T03:33:21.123456789Z"}"#;
+    assert_eq!(serde_json::from_str::<Published>(zulu).unwrap(), event);
+    let shifted = r#"{"at":"2024-12-14T05:33:21.123456789+02:00"}"#;
+    assert_eq!(serde_json::from_str::<Published>(shifted).unwrap(), event);
+    assert!(serde_json::from_str::<Published>(r#"{"at":"2024-12-14T03:33:21"}"#).is_err());
+}
+
+#[test]
+fn test_serde_precision_round_trip() {
+    use universal_timestamp::Precision;
+    for (precision, name) in [
+        (Precision::Nanosecond, "\"nanosecond\""),
+        (Precision::Microsecond, "\"microsecond\""),
+        (Precision::Millisecond, "\"millisecond\""),
+        (Precision::Second, "\"second\""),
+    ] {
+        assert_eq!(serde_json::to_string(&precision).unwrap(), name);
+        assert_eq!(serde_json::from_str::<Precision>(name).unwrap(), precision);
+    }
+}
+
+#[test]
+fn test_serde_precision_rejects_unknown() {
+    use universal_timestamp::Precision;
+    for input in ["\"minute\"", "\"Millisecond\"", "\"error\"", "2"] {
+        assert!(serde_json::from_str::<Precision>(input).is_err(), "{}", input);
+    }
+    assert!(serde_json::to_string(&Precision::Error).is_err());
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Click {
+    #[serde(with = "universal_timestamp::serde_millis")]
+    at: Timestamp,
+}
+
+#[test]
+fn test_serde_millis_round_trip() {
+    let click = Click { at: Timestamp::from_nanos(1_734_177_600_123_000_000) };
+    let json = serde_json::to_string(&click).unwrap();
+    assert_eq!(json, r#"{"at":1734177600123}"#);
+    assert_eq!(serde_json::from_str::<Click>(&json).unwrap(), click);
+
+    // Sub-millisecond digits are dropped, rounding toward negative infinity.
+    let fine = Click { at: Timestamp::from_nanos(-1) };
+    assert_eq!(serde_json::to_string(&fine).unwrap(), r#"{"at":-1}"#);
+    let back = serde_json::from_str::<Click>(r#"{"at":-1}"#).unwrap();
+    assert_eq!(back.at.as_nanos(), -1_000_000);
+}
+
+#[test]
+fn test_serde_millis_rejects_fractions() {
+    assert!(serde_json::from_str::<Click>(r#"{"at":1.5}"#).is_err());
+    assert!(serde_json::from_str::<Click>(r#"{"at":"1734177600123"}"#).is_err());
+    assert!(serde_json::from_str::<Click>(r#"{"at":9223372036854775807}"#).is_err());
+}
@@ -0,0 +1,34 @@
+#![cfg(all(feature = "time", feature = "alloc"))]
+
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use universal_timestamp::Timestamp;
+
+fn utc(year: i32, month: Month, day: u8, nanos: u32) -> OffsetDateTime {
+    let date = Date::from_calendar_date(year, month, day).unwrap();
+    let time = Time::from_hms_nano(12, 0, 0, nanos).unwrap();
+    PrimitiveDateTime::new(date, time).assume_utc()
+}
+
+#[test]
+fn test_time_round_trip() {
+    let dt = utc(2024, Month::December, 14, 123_456_789);
+    let ts = Timestamp::from(dt);
+    assert_eq!(ts.format(true), "2024-12-14T12:00:00.123456789Z");
+    assert_eq!(OffsetDateTime::try_from(ts).unwrap(), dt);
+}
+
+#[test]
+fn test_time_offset_normalized() {
+    let dt = utc(2024, Month::December, 14, 0).to_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+    let ts = Timestamp::from(dt);
+    assert_eq!(ts.format(false), "2024-12-14T12:00:00Z");
+    assert_eq!(OffsetDateTime::try_from(ts).unwrap().offset(), UtcOffset::UTC);
+}
+
+#[test]
+fn test_time_boundaries() {
+    assert_eq!(Timestamp::from(OffsetDateTime::try_from(Timestamp::MIN).unwrap()), Timestamp::MIN);
+    assert_eq!(Timestamp::from(OffsetDateTime::try_from(Timestamp::MAX).unwrap()), Timestamp::MAX);
+    assert_eq!(Timestamp::from(utc(3000, Month::January, 1, 0)), Timestamp::MAX);
+    assert_eq!(Timestamp::from(utc(1000, Month::January, 1, 0)), Timestamp::MIN);
+}
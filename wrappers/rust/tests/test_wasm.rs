@@ -0,0 +1,21 @@
+#![cfg(all(feature = "wasm", feature = "alloc", target_arch = "wasm32"))]
+
+use universal_timestamp::Timestamp;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn test_wasm_now() {
+    let ts = Timestamp::now();
+    assert!(ts.is_plausible());
+    assert_eq!(ts.as_nanos() % 1_000_000, 0);
+    assert!(Timestamp::try_now().is_ok());
+}
+
+#[wasm_bindgen_test]
+fn test_wasm_format() {
+    let ts = Timestamp::from_nanos(1_734_177_600_000_000_000);
+    assert_eq!(ts.format(false), "2024-12-14T12:00:00Z");
+    assert_eq!(Timestamp::parse("2024-12-14T12:00:00Z").unwrap(), ts);
+}